@@ -0,0 +1,3552 @@
+//! Derive macro for [prkorm](https://docs.rs/prkorm). Depend on `prkorm` and use `prkorm::Table`
+//! instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input,  Data, DeriveInput, Fields,
+    Ident, LitInt, LitStr, Token,
+};
+
+
+
+#[proc_macro_derive(Table, attributes(table_name, table_schema, primary_key, table_alias, sharded_by, tenant_key, version, table, uuid_binary, generated, select_only, never_select, default_select, column, datetime))]
+pub fn table_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree.
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &ast.ident;
+    let mut builder = Ident::new(&format!("{}SelectBuilder", struct_name), struct_name.span());
+    let mut insert_builder = Ident::new(&format!("{}InsertBuilder", struct_name), struct_name.span());
+    let mut update_builder = Ident::new(&format!("{}UpdateBuilder", struct_name), struct_name.span());
+    let mut delete_builder = Ident::new(&format!("{}DeleteBuilder", struct_name), struct_name.span());
+    let mut column_enum = Ident::new(&format!("{}Column", struct_name), struct_name.span());
+
+    let fields = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named_fields) => &named_fields.named,
+            _ => return quote! {}.into(),
+        },
+        _ => return quote! {}.into(),
+    };
+
+    // Extract the "table_name" attribute if present.
+    let table_name_attr = ast.attrs.iter().find(|attr| {
+        if let Some(ident) = attr.path().get_ident() {
+            ident == "table_name"
+        } else {
+            false
+        }
+    });
+    let table_name_as_attr = ast.attrs.iter().find(|attr| {
+        if let Some(ident) = attr.path().get_ident() {
+            ident == "table_alias"
+        } else {
+            false
+        }
+    });
+
+    // Extract the value of the "table_name" attribute, if present.
+    let table: Option<String> = if let Some(attr) = table_name_attr {
+        if let Ok(lit) = attr.parse_args::<LitStr>() {
+            Some(lit.value())
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    // Extract the value of the "table_name" attribute, if present.
+    let table_as: Option<String> = if let Some(attr) = table_name_as_attr {
+        if let Ok(lit) = attr.parse_args::<LitStr>() {
+            Some(lit.value())
+        } else {
+            table.clone()
+        }
+    } else {
+        table.clone()
+    };
+
+    // With the `serde` feature, builders can be cached or sent elsewhere and
+    // rebuilt later; the per-builder observer is not part of the query.
+    let (serde_derive, serde_skip) = match cfg!(feature = "serde") {
+        true => (
+            quote! {
+                #[derive(::prkorm::serde::Serialize, ::prkorm::serde::Deserialize)]
+                #[serde(crate = "::prkorm::serde")]
+            },
+            quote!(#[serde(skip)]),
+        ),
+        false => (quote! {}, quote! {}),
+    };
+
+    // Extract the value of the "table_schema" attribute, if present.
+    let table_schema: Option<String> = ast
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("table_schema"))
+        .and_then(|attr| attr.parse_args::<LitStr>().ok())
+        .map(|lit| lit.value());
+    let table_naming = match &table_schema {
+        Some(schema) => quote!(::prkorm::TableNaming::default().with_schema(#schema)),
+        None => quote!(::prkorm::TableNaming::default()),
+    };
+
+    // Extract the "primary_key" attribute if present.
+    let primary_key_attr = ast.attrs.iter().find(|attr| {
+        if let Some(ident) = attr.path().get_ident() {
+            ident == "primary_key"
+        } else {
+            false
+        }
+    });
+
+    // Extract the value of the "table_name" attribute, if present.
+    let primary_key_var = match primary_key_attr.map(|attr| attr.parse_args::<LitStr>()) {
+        Some(Ok(lit)) => lit.value(),
+        Some(Err(error)) => return error.to_compile_error().into(),
+        None => String::new(),
+    };
+    if let Some(attr) = primary_key_attr {
+        if !fields.iter().any(|f| f.ident.as_ref().unwrap() == &primary_key_var) {
+            return syn::Error::new_spanned(attr, format!("primary key `{}` is not a field", primary_key_var))
+                .to_compile_error()
+                .into();
+        }
+    }
+
+    // `#[primary_key]` can also mark the field itself.
+    let primary_key_fields: Vec<&syn::Field> = fields
+        .iter()
+        .filter(|f| f.attrs.iter().any(|attr| attr.path().is_ident("primary_key")))
+        .collect();
+    if let Some(field) = primary_key_fields.get(1) {
+        return syn::Error::new_spanned(field, "only one field can be marked #[primary_key]")
+            .to_compile_error()
+            .into();
+    }
+    let primary_key_var = match primary_key_fields.first() {
+        Some(field) => {
+            let attr = field.attrs.iter().find(|attr| attr.path().is_ident("primary_key")).unwrap();
+            if let Err(error) = attr.meta.require_path_only() {
+                return error.to_compile_error().into();
+            }
+            if primary_key_attr.is_some() {
+                return syn::Error::new_spanned(attr, "the primary key is already set with #[primary_key(\"...\")] on the struct")
+                    .to_compile_error()
+                    .into();
+            }
+            field.ident.as_ref().unwrap().to_string()
+        }
+        None => primary_key_var,
+    };
+
+    // Extract the `#[table(...)]` options.
+    let mut repository = false;
+    let mut vis: syn::Visibility = syn::parse_quote!(pub);
+    let mut module = false;
+    let mut convention = String::from("snake_case");
+    let mut pluralize = false;
+    let mut default_limit: Option<u32> = None;
+    let mut table_options = String::new();
+    let mut ops = Operations { select: true, insert: true, update: true, delete: true };
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("table")) {
+        let parsed = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("repository") {
+                repository = true;
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                let value: LitStr = meta.value()?.parse()?;
+                vis = value.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("select_builder") {
+                builder = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("insert_builder") {
+                insert_builder = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("update_builder") {
+                update_builder = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("delete_builder") {
+                delete_builder = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("column_enum") {
+                column_enum = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("convention") {
+                let value: LitStr = meta.value()?.parse()?;
+                match value.value().as_str() {
+                    "snake_case" | "lowercase" | "exact" => {
+                        convention = value.value();
+                        Ok(())
+                    }
+                    _ => Err(syn::Error::new(value.span(), "expected \"snake_case\", \"lowercase\" or \"exact\"")),
+                }
+            } else if meta.path.is_ident("pluralize") {
+                pluralize = true;
+                Ok(())
+            } else if meta.path.is_ident("default_limit") {
+                let value: LitInt = meta.value()?.parse()?;
+                let limit = value.base10_parse::<u32>()?;
+                if limit == 0 {
+                    return Err(syn::Error::new(value.span(), "`default_limit` must be at least 1"));
+                }
+                default_limit = Some(limit);
+                Ok(())
+            } else if meta.path.is_ident("engine") {
+                let value: LitStr = meta.value()?.parse()?;
+                table_options = format!("{} ENGINE={}", table_options, value.value());
+                Ok(())
+            } else if meta.path.is_ident("charset") {
+                let value: LitStr = meta.value()?.parse()?;
+                table_options = format!("{} DEFAULT CHARSET={}", table_options, value.value());
+                Ok(())
+            } else if meta.path.is_ident("collate") {
+                let value: LitStr = meta.value()?.parse()?;
+                table_options = format!("{} COLLATE={}", table_options, value.value());
+                Ok(())
+            } else if meta.path.is_ident("comment") {
+                let value: LitStr = meta.value()?.parse()?;
+                table_options = format!("{} COMMENT={}", table_options, sql_string(&value.value()));
+                Ok(())
+            } else if meta.path.is_ident("module") {
+                module = true;
+                Ok(())
+            } else if meta.path.is_ident("ops") {
+                ops = Operations { select: false, insert: false, update: false, delete: false };
+                meta.parse_nested_meta(|op| {
+                    if op.path.is_ident("select") {
+                        ops.select = true;
+                    } else if op.path.is_ident("insert") {
+                        ops.insert = true;
+                    } else if op.path.is_ident("update") {
+                        ops.update = true;
+                    } else if op.path.is_ident("delete") {
+                        ops.delete = true;
+                    } else {
+                        return Err(op.error("expected `select`, `insert`, `update` or `delete`"));
+                    }
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unsupported table option"))
+            }
+        });
+        if let Err(error) = parsed {
+            return error.to_compile_error().into();
+        }
+    }
+    if module {
+        vis = nested_visibility(&vis);
+    }
+
+    // Without `#[table_name]`, infer the table from the struct name.
+    let table = table.or_else(|| {
+        let name = match convention.as_str() {
+            "lowercase" => struct_name.to_string().to_lowercase(),
+            "exact" => struct_name.to_string(),
+            _ => to_snake_case(&struct_name.to_string()),
+        };
+        match pluralize {
+            true => Some(to_plural(&name)),
+            false => Some(name),
+        }
+    });
+    let table_as = table_as.or_else(|| table.clone());
+    let default_limit = match default_limit {
+        Some(limit) => quote! { .limit(#limit) },
+        None => quote! {},
+    };
+
+    // Extract `#[sharded_by("column", shards = N)]`, if present.
+    let sharded_by_attr = ast.attrs.iter().find(|attr| {
+        if let Some(ident) = attr.path().get_ident() {
+            ident == "sharded_by"
+        } else {
+            false
+        }
+    });
+    let sharding: Option<(String, u32)> = match sharded_by_attr {
+        Some(attr) => {
+            let parsed = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let column: LitStr = input.parse()?;
+                input.parse::<Token![,]>()?;
+                let key: Ident = input.parse()?;
+                if key != "shards" {
+                    return Err(syn::Error::new(key.span(), "expected `shards = <count>`"));
+                }
+                input.parse::<Token![=]>()?;
+                let shards: LitInt = input.parse()?;
+                let count = shards.base10_parse::<u32>()?;
+                if count == 0 {
+                    return Err(syn::Error::new(shards.span(), "`shards` must be at least 1"));
+                }
+                if !fields.iter().any(|f| f.ident.as_ref().unwrap() == &column.value()) {
+                    return Err(syn::Error::new(column.span(), format!("unknown field `{}`", column.value())));
+                }
+                Ok((column.value(), count))
+            });
+            match parsed {
+                Ok(sharding) => Some(sharding),
+                Err(error) => return error.to_compile_error().into(),
+            }
+        }
+        None => None,
+    };
+    let (shard_builder_function, shard_model_functions) = match &sharding {
+        Some((shard_key, shards)) => (
+            quote! {
+                pub fn shard_for(self, value: impl ToString) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_suffix(&::prkorm::shard_suffix(&value.to_string(), #shards)),
+                        ..self
+                    }
+                }
+            },
+            quote! {
+                #vis fn shard_key() -> &'static str {
+                    #shard_key
+                }
+
+                #vis fn shard_count() -> u32 {
+                    #shards
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+
+    // Extract `#[tenant_key("column")]`, if present.
+    let tenant_key_attr = ast.attrs.iter().find(|attr| attr.path().is_ident("tenant_key"));
+    let tenant_key: Option<String> = match tenant_key_attr {
+        Some(attr) => {
+            let parsed = attr.parse_args::<LitStr>().and_then(|column| {
+                if !fields.iter().any(|f| f.ident.as_ref().unwrap() == &column.value()) {
+                    return Err(syn::Error::new(column.span(), format!("unknown field `{}`", column.value())));
+                }
+                Ok(column.value())
+            });
+            match parsed {
+                Ok(column) => Some(column),
+                Err(error) => return error.to_compile_error().into(),
+            }
+        }
+        None => None,
+    };
+
+    if !cfg!(feature = "uuid") {
+        if let Some(field) = fields.iter().find(|f| is_uuid_binary(f)) {
+            return syn::Error::new_spanned(field, "#[uuid_binary] requires the `uuid` feature of prkorm")
+                .to_compile_error()
+                .into();
+        }
+    }
+
+    // Extract the `#[version]` field used for optimistic locking, if present.
+    let version_fields: Vec<&syn::Field> = fields
+        .iter()
+        .filter(|f| f.attrs.iter().any(|attr| attr.path().is_ident("version")))
+        .collect();
+    if version_fields.len() > 1 {
+        return syn::Error::new_spanned(version_fields[1], "only one field can be marked #[version]")
+            .to_compile_error()
+            .into();
+    }
+    if let Some(field) = version_fields.iter().find(|f| is_read_only(f)) {
+        return syn::Error::new_spanned(field, "a #[version] field cannot be #[generated] or #[select_only]")
+            .to_compile_error()
+            .into();
+    }
+    if !version_fields.is_empty() && !ops.update {
+        return syn::Error::new_spanned(version_fields[0], "#[version] requires the `update` operation")
+            .to_compile_error()
+            .into();
+    }
+    if repository && !(ops.select && ops.insert && ops.delete) {
+        return syn::Error::new_spanned(struct_name, "#[table(repository)] requires the `select`, `insert` and `delete` operations")
+            .to_compile_error()
+            .into();
+    }
+    if !version_fields.is_empty() && primary_key_var.is_empty() {
+        return syn::Error::new_spanned(version_fields[0], "#[version] requires a #[primary_key(\"...\")]")
+            .to_compile_error()
+            .into();
+    }
+    let primary_key_sql = sql_identifier(&primary_key_var);
+    let (version_builder_function, version_model_functions) = match version_fields.first() {
+        Some(version_field) => {
+            let version_ident = version_field.ident.as_ref().unwrap();
+            let version_column = sql_identifier(&version_ident.to_string());
+            let primary_key_field = match fields.iter().find(|f| f.ident.as_ref().unwrap() == &primary_key_var) {
+                Some(f) => f,
+                None => {
+                    return syn::Error::new_spanned(version_field, format!("primary key `{}` is not a field", primary_key_var))
+                        .to_compile_error()
+                        .into()
+                }
+            };
+            let primary_key_ident = primary_key_field.ident.as_ref().unwrap();
+            let primary_key_literal = field_literal(primary_key_field, quote!(self));
+            let version_literal = field_literal(version_field, quote!(self));
+            let set_fields: Vec<&syn::Field> = fields
+                .iter()
+                .filter(|f| !is_read_only(f))
+                .filter(|f| f.ident.as_ref() != Some(version_ident) && f.ident.as_ref() != Some(primary_key_ident))
+                .collect();
+            let set_columns: Vec<String> = set_fields
+                .iter()
+                .map(|f| sql_identifier(&f.ident.as_ref().unwrap().to_string()))
+                .collect();
+            let set_literals: Vec<proc_macro2::TokenStream> = set_fields
+                .iter()
+                .map(|f| field_literal(f, quote!(self)))
+                .collect();
+            (
+                quote! {
+                    fn versioned(self, version_column: &str, where_condition: String) -> String {
+                        let set_values = match self.selected.is_empty() {
+                            true => format!("{} = {} + 1", version_column, version_column),
+                            false => format!("{}, {} = {} + 1", self.set_values(), version_column, version_column),
+                        };
+                        self.finish(|this| format!("UPDATE {} SET {} \nWHERE {}", this.update_target(), set_values, where_condition))
+                    }
+                },
+                quote! {
+                    #vis fn update_from(&self) -> String {
+                        let mut builder = Self::update();
+                        #(builder.selected.insert(String::from(#set_columns), #set_literals);)*
+                        builder.versioned(#version_column, format!(
+                            "{} = {} AND {} = {}",
+                            #primary_key_sql,
+                            #primary_key_literal,
+                            #version_column,
+                            #version_literal
+                        ))
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    let repository_trait = if repository {
+        let repository_ident = Ident::new(&format!("{}Repository", struct_name), struct_name.span());
+        let primary_key_field = match fields.iter().find(|f| f.ident.as_ref().unwrap() == &primary_key_var) {
+            Some(f) => f,
+            None => {
+                return syn::Error::new_spanned(struct_name, "#[table(repository)] requires a #[primary_key(\"...\")] naming a field")
+                    .to_compile_error()
+                    .into()
+            }
+        };
+        let primary_key_ident = primary_key_field.ident.as_ref().unwrap();
+        let primary_key_ty = &primary_key_field.ty;
+        let where_primary_key = Ident::new(&format!("where_{}", primary_key_ident), primary_key_ident.span());
+        let delete_where_primary_key = Ident::new(&format!("delete_where_{}_eq", primary_key_ident), primary_key_ident.span());
+        let insert_fields: Vec<&syn::Field> = fields.iter().filter(|f| !is_read_only(f)).collect();
+        let insert_columns: Vec<String> = insert_fields
+            .iter()
+            .map(|f| sql_identifier(&f.ident.as_ref().unwrap().to_string()))
+            .collect();
+        let insert_literals: Vec<proc_macro2::TokenStream> =
+            insert_fields.iter().map(|f| field_literal(f, quote!(model))).collect();
+        quote! {
+            #vis trait #repository_ident {
+                fn find_by_id(&self, id: #primary_key_ty) -> String {
+                    #struct_name::select().#where_primary_key(id).build()
+                }
+
+                fn all(&self) -> String {
+                    #struct_name::select().build()
+                }
+
+                fn delete_by_id(&self, id: #primary_key_ty) -> String {
+                    #struct_name::delete().#delete_where_primary_key(id)
+                }
+
+                fn insert_one(&self, model: &#struct_name) -> String {
+                    let mut builder = #struct_name::insert();
+                    #(builder.selected.insert(String::from(#insert_columns), vec![#insert_literals]);)*
+                    builder.build()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let table_dot =  match table.clone() { Some(name) =>{
+            match table_as {
+                Some(ref alias) => format!("{}.", alias), 
+                None =>   format!("{}.", name)
+            }
+        }, None => String::new()};
+
+    // `#[tenant_key]` models scope every select, update and delete builder to
+    // the tenant that is current when the builder is built.
+    let (tenant_column, tenant_model_functions) = match &tenant_key {
+        Some(column) => {
+            let column_sql = sql_identifier(column);
+            (
+                quote! { Some(String::from(#column_sql)) },
+                quote! {
+                    #vis fn tenant_key() -> &'static str {
+                        #column
+                    }
+                },
+            )
+        }
+        None => (quote! { None }, quote! {}),
+    };
+
+    // Column definitions for `create_table_sql()`, with `#[column(...)]`
+    // overriding the SQL type or adding a comment.
+    let mut column_definitions = Vec::new();
+    for field in fields {
+        let mut sql_type = sql_column_type(field);
+        let mut comment = String::new();
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("column")) {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sql_type") {
+                    sql_type = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("comment") {
+                    comment = format!(" COMMENT {}", sql_string(&meta.value()?.parse::<LitStr>()?.value()));
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `sql_type` or `comment`"))
+                }
+            });
+            if let Err(error) = parsed {
+                return error.to_compile_error().into();
+            }
+        }
+        let nullable = match is_option(&field.ty) {
+            true => "NULL",
+            false => "NOT NULL",
+        };
+        column_definitions.push(format!("  {} {} {}{}", sql_identifier(&field.ident.as_ref().unwrap().to_string()), sql_type, nullable, comment));
+    }
+    if !primary_key_var.is_empty() {
+        column_definitions.push(format!("  PRIMARY KEY ({})", primary_key_sql));
+    }
+    let column_definitions = column_definitions.join(",\n");
+
+    if !fields.is_empty() && fields.iter().all(is_never_selected) {
+        return syn::Error::new_spanned(struct_name, "at least one field must be selectable without #[never_select]")
+            .to_compile_error()
+            .into();
+    }
+    // `#[never_select]` fields (secrets such as password hashes) are left out
+    // of `select()` and can only be selected explicitly.
+    let projection = |f: &syn::Field| {
+        let name = sql_identifier(&f.ident.as_ref().unwrap().to_string());
+        if is_uuid_binary(f) {
+            format!("BIN_TO_UUID({}{}) AS {}", &table_dot, name, name)
+        } else {
+            format!("{}{}", &table_dot, name)
+        }
+    };
+    let field_names = fields
+        .iter()
+        .filter(|f| !is_never_selected(f))
+        .map(projection)
+        .reduce(|acc, x| format!("{}, {}", acc, x))
+        .unwrap_or(String::from("*"));
+
+    // `#[default_select("id", ...)]` narrows `select()`; `select_all_columns()`
+    // keeps the full projection.
+    let default_field_names = match ast.attrs.iter().find(|attr| attr.path().is_ident("default_select")) {
+        Some(attr) => {
+            let names = match attr.parse_args_with(syn::punctuated::Punctuated::<LitStr, Token![,]>::parse_terminated) {
+                Ok(names) => names,
+                Err(error) => return error.to_compile_error().into(),
+            };
+            if names.is_empty() {
+                return syn::Error::new_spanned(attr, "#[default_select] needs at least one field")
+                    .to_compile_error()
+                    .into();
+            }
+            let mut selected = Vec::new();
+            for name in names {
+                match fields.iter().find(|f| f.ident.as_ref().unwrap() == &name.value()) {
+                    Some(f) => selected.push(projection(f)),
+                    None => {
+                        return syn::Error::new(name.span(), format!("`{}` is not a field", name.value()))
+                            .to_compile_error()
+                            .into()
+                    }
+                }
+            }
+            selected.join(", ")
+        }
+        None => field_names.clone(),
+    };
+
+    // `select()` with the default naming always starts with the same text, so
+    // render it once here; `render()` falls back to formatting when the
+    // projection, keyword or FROM clause differ.
+    let default_from = match (&table, &table_as) {
+        (Some(name), Some(alias)) if name != alias => format!("{} {}", name, alias),
+        (Some(name), _) => format!("{} ", name),
+        _ => String::new(),
+    };
+    let select_prefix = format!("SELECT {} \nFROM {}", default_field_names, default_from);
+
+    let column_names: Vec<String> = fields
+        .iter()
+        .map(|f| sql_identifier(&f.ident.as_ref().unwrap().to_string()))
+        .collect();
+    let qualified_column_names: Vec<String> = column_names
+        .iter()
+        .map(|name| format!("{}{}", &table_dot, name))
+        .collect();
+    let field_count = column_names.len();
+    let selectable_column_names: Vec<String> = fields
+        .iter()
+        .filter(|f| !is_never_selected(f))
+        .map(|f| sql_identifier(&f.ident.as_ref().unwrap().to_string()))
+        .collect();
+    let never_selected_names: Vec<String> = fields
+        .iter()
+        .filter(|f| is_never_selected(f))
+        .map(|f| f.ident.as_ref().unwrap().to_string())
+        .collect();
+
+    let mut field_functions = Vec::new();
+    let mut insert_functions = Vec::new();
+    let mut update_functions = Vec::new();
+    let mut delete_functions = Vec::new();
+    let mut derived_functions = Vec::new();
+    let mut column_variants = Vec::new();
+    let mut column_qualified_arms = Vec::new();
+    let mut column_name_arms = Vec::new();
+    let mut column_from_name_arms = Vec::new();
+
+    
+    
+
+    if !primary_key_var.is_empty() {
+        field_functions.push(quote!(
+
+            pub fn inner_join(mut self, table: &str,  primary_key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+              let this_table =   &self.table_alias ;
+                conditions.push(format!("\nINNER JOIN {} ON {}.{} = {}.{}", table, table, primary_key, this_table,  self.primary_key,));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn join(mut self,  table: &str, primary_key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                let this_table =   &self.table_alias ;
+                conditions.push(format!("\nJOIN {} ON {}.{} = {}.{}", table, table, primary_key, this_table, self.primary_key));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn left_join(mut self, table: &str,  primary_key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                let this_table =  &self.table_alias;
+                conditions.push(format!("\nLEFT JOIN {} ON {}.{} = {}.{}", table, table, primary_key,  this_table, self.primary_key));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn right_join(mut self,  table: &str, primary_key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                let this_table =  &self.table_alias;
+                conditions.push(format!("\nRIGHT JOIN {} ON {}.{} = {}.{}", table, table, primary_key,  this_table, self.primary_key));
+               
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn inner_join_model_as<M: ::prkorm::Model>(mut self, alias: &str, key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nINNER JOIN {} {} ON {}.{} = {}.{}", M::model_table(), alias, alias, key, self.table_alias, self.primary_key));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn left_join_model_as<M: ::prkorm::Model>(mut self, alias: &str, key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nLEFT JOIN {} {} ON {}.{} = {}.{}", M::model_table(), alias, alias, key, self.table_alias, self.primary_key));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn full_join(mut self, table: &str,  primary_key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                let this_table =  &self.table_alias;
+                conditions.push(format!("\nRIGHT JOIN {} ON {}.{} = {}.{}", table, table, primary_key,  this_table, self.primary_key));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+
+        ))
+    }
+
+
+    for field in fields {
+       
+
+        let field_name = field.ident.as_ref().unwrap();
+        let (value_bound, to_literal) = value_rendering(field);
+        // let field_ty = &field.ty;
+
+        let get_field_name_in_use = Ident::new(&format!("{}", field_name), field_name.span());
+        let column_variant = Ident::new(&to_pascal_case(&field_name.to_string()), field_name.span());
+        let column_const = Ident::new(&format!("COL_{}", field_name.to_string().to_uppercase()), field_name.span());
+
+        // Reserved words such as `order` or `key` are backtick-quoted in SQL.
+        let field_name_without_table = sql_identifier(&field_name.to_string());
+        let field_name_with_table = format!("{}{}", &table_dot, field_name_without_table);
+        // Binary UUID columns are read back in their text form.
+        let (select_with_table, column_read, select_suffix) = if is_uuid_binary(field) {
+            (
+                format!("BIN_TO_UUID({}) AS {}", field_name_with_table, field_name_without_table),
+                quote!(format!("BIN_TO_UUID({})", self.column(#field_name_without_table))),
+                format!(" AS {}", field_name_without_table),
+            )
+        } else {
+            (field_name_with_table.clone(), quote!(self.column(#field_name_without_table)), String::new())
+        };
+ 
+        let select_field_name = Ident::new(&format!("select_{}", field_name), field_name.span());
+        
+        let select_field_name_as = Ident::new(&format!("select_{}_as", field_name), field_name.span());
+        
+        let select_function_over_field_name = Ident::new(&format!("select_function_over_{}", field_name), field_name.span());
+       
+        let select_function_over_field_name_as = Ident::new(&format!("select_function_over_{}_as", field_name), field_name.span());
+
+        let insert_into_col = Ident::new(&format!("insert_to_{}", field_name), field_name.span());
+        
+        let delete_where_col = Ident::new(&format!("delete_where_{}_eq", field_name), field_name.span());
+        let delete_where_condition = Ident::new(&format!("delete_where_{}_condition", field_name), field_name.span());
+        let delete_where_gt = Ident::new(&format!("delete_where_{}_gt", field_name), field_name.span());
+        let delete_where_lt = Ident::new(&format!("delete_where_{}_lt", field_name), field_name.span());
+        let delete_where_like = Ident::new(&format!("delete_where_{}_like", field_name), field_name.span());
+        let delete_where_is_null = Ident::new(&format!("delete_where_{}_is_null", field_name), field_name.span());
+
+        let update_where_col = Ident::new(&format!("update_where_{}_eq", field_name), field_name.span());
+        let update_where_condition = Ident::new(&format!("update_where_{}_condition", field_name), field_name.span());
+        let update_where_gt = Ident::new(&format!("update_where_{}_gt", field_name), field_name.span());
+        let update_where_lt = Ident::new(&format!("update_where_{}_lt", field_name), field_name.span());
+        let update_where_like = Ident::new(&format!("update_where_{}_like", field_name), field_name.span());
+        let update_where_is_null = Ident::new(&format!("update_where_{}_is_null", field_name), field_name.span());
+        let update_col_with_value = Ident::new(&format!("update_{}_with_value", field_name), field_name.span());
+        let update_col_with_interval_add = Ident::new(&format!("update_{}_with_interval_add", field_name), field_name.span());
+        let update_col_with_interval_sub = Ident::new(&format!("update_{}_with_interval_sub", field_name), field_name.span());
+
+        let inner_join = Ident::new(&format!("inner_join_by_{}", field_name), field_name.span());
+        let join = Ident::new(&format!("join_by_{}", field_name), field_name.span());
+        let left_join = Ident::new(&format!("left_join_by_{}", field_name), field_name.span());
+        let right_join = Ident::new(&format!("right_join_by_{}", field_name), field_name.span());
+        let full_join = Ident::new(&format!("full_join_by_{}", field_name), field_name.span());
+        let inner_join_model_as = Ident::new(&format!("inner_join_by_{}_model_as", field_name), field_name.span());
+        let left_join_model_as = Ident::new(&format!("left_join_by_{}_model_as", field_name), field_name.span());
+        let column_of = Ident::new(&format!("{}_of", field_name), field_name.span());
+
+
+        let where_function_name_in = Ident::new(&format!("where_{}_in", field_name), field_name.span());
+        let where_any = Ident::new(&format!("where_{}_any", field_name), field_name.span());
+        let where_all = Ident::new(&format!("where_{}_all", field_name), field_name.span());
+        let where_gt_any = Ident::new(&format!("where_{}_gt_any", field_name), field_name.span());
+        let where_lt_any = Ident::new(&format!("where_{}_lt_any", field_name), field_name.span());
+        let where_gt_all = Ident::new(&format!("where_{}_gt_all", field_name), field_name.span());
+        let where_lt_all = Ident::new(&format!("where_{}_lt_all", field_name), field_name.span());
+        let where_function_name = Ident::new(&format!("where_{}", field_name), field_name.span());
+        let group_by_function = Ident::new(&format!("group_by_{}", field_name), field_name.span());
+        let order_by_function = Ident::new(&format!("order_by_{}", field_name), field_name.span());
+        let order_by_asc_function = Ident::new(&format!("order_by_{}_asc", field_name), field_name.span());
+        let order_by_desc_function = Ident::new(&format!("order_by_{}_desc", field_name), field_name.span());
+        let order_by_dir_function = Ident::new(&format!("order_by_{}_dir", field_name), field_name.span());
+        let order_by_collate_function = Ident::new(&format!("order_by_{}_collate", field_name), field_name.span());
+        let where_eq_collate = Ident::new(&format!("where_{}_eq_collate", field_name), field_name.span());
+        let order_by_asc_nulls_first_function = Ident::new(&format!("order_by_{}_asc_nulls_first", field_name), field_name.span());
+        let order_by_asc_nulls_last_function = Ident::new(&format!("order_by_{}_asc_nulls_last", field_name), field_name.span());
+        let order_by_desc_nulls_first_function = Ident::new(&format!("order_by_{}_desc_nulls_first", field_name), field_name.span());
+        let order_by_desc_nulls_last_function = Ident::new(&format!("order_by_{}_desc_nulls_last", field_name), field_name.span());
+        let having_function = Ident::new(&format!("having_{}", field_name), field_name.span());
+        let or_having_function = Ident::new(&format!("or_having_{}", field_name), field_name.span());
+        let having_function_in = Ident::new(&format!("having_{}_in", field_name), field_name.span());
+        let where_function_operator_name = Ident::new(
+            &format!("where_{}_condition", field_name),
+            field_name.span(),
+        );
+
+        column_variants.push(quote!(#column_variant));
+        column_qualified_arms.push(quote!(#column_enum::#column_variant => #field_name_with_table));
+        column_name_arms.push(quote!(#column_enum::#column_variant => #field_name_without_table));
+        let raw_field_name = field_name.to_string();
+        column_from_name_arms.push(quote!(#raw_field_name => Some(#column_enum::#column_variant)));
+
+        delete_functions.push(quote! {
+            pub fn #delete_where_col(mut self, value: impl #value_bound) -> String {
+                self.finish(|this| format!("DELETE {} WHERE {} = {}", this.delete_target(), #field_name_without_table, #to_literal(&value)))
+            }
+
+            pub fn #delete_where_condition(mut self, operator: &str, value: impl #value_bound) -> String {
+                self.finish(|this| format!("DELETE {} WHERE {} {} {}", this.delete_target(), #field_name_without_table, operator, #to_literal(&value)))
+            }
+
+            pub fn #delete_where_gt(self, value: impl #value_bound) -> String {
+                self.#delete_where_condition(">", value)
+            }
+
+            pub fn #delete_where_lt(self, value: impl #value_bound) -> String {
+                self.#delete_where_condition("<", value)
+            }
+
+            pub fn #delete_where_like(mut self, pattern: impl ToString) -> String {
+                self.finish(|this| format!("DELETE {} WHERE {} LIKE {}", this.delete_target(), #field_name_without_table, ::prkorm::literal(&pattern)))
+            }
+
+            pub fn #delete_where_is_null(mut self) -> String {
+                self.finish(|this| format!("DELETE {} WHERE {} IS NULL", this.delete_target(), #field_name_without_table))
+            }
+        });
+
+        update_functions.push(quote! {
+              pub fn #update_where_col(mut self, value: impl #value_bound) -> String {
+                self.finish(|this| format!("UPDATE {} SET {} \nWHERE {} = {}", this.update_target(), this.set_values(),  #field_name_without_table.clone(), #to_literal(&value)))
+              }  
+
+              pub fn #update_where_condition(mut self, operator: &str, value: impl #value_bound) -> String {
+                self.finish(|this| format!("UPDATE {} SET {} \nWHERE {} {} {}", this.update_target(), this.set_values(), #field_name_without_table, operator, #to_literal(&value)))
+              }
+
+              pub fn #update_where_gt(self, value: impl #value_bound) -> String {
+                self.#update_where_condition(">", value)
+              }
+
+              pub fn #update_where_lt(self, value: impl #value_bound) -> String {
+                self.#update_where_condition("<", value)
+              }
+
+              pub fn #update_where_like(mut self, pattern: impl ToString) -> String {
+                self.finish(|this| format!("UPDATE {} SET {} \nWHERE {} LIKE {}", this.update_target(), this.set_values(), #field_name_without_table, ::prkorm::literal(&pattern)))
+              }
+
+              pub fn #update_where_is_null(mut self) -> String {
+                self.finish(|this| format!("UPDATE {} SET {} \nWHERE {} IS NULL", this.update_target(), this.set_values(), #field_name_without_table))
+              }
+            }
+        );
+
+        // Generated and trigger-maintained columns cannot be written.
+        if !is_read_only(field) {
+            update_functions.push(quote! {
+              pub fn #update_col_with_value(mut self, value: impl #value_bound) -> Self {
+                let mut selected =  self.selected.clone();
+                 selected.entry(#field_name_without_table.to_string()).or_insert(#to_literal(&value));
+                Self {
+                    selected: selected,
+                    ..self
+                }
+              }
+            });
+
+            insert_functions.push(quote! {
+            pub fn #insert_into_col(mut self, value : impl #value_bound) -> Self {
+                let mut selected =  self.selected.clone();
+                 selected.entry(#field_name_without_table.to_string()).or_insert(vec![#to_literal(&value)]);
+                Self {
+                    selected: selected,
+                    ..self
+                }
+            }
+            });
+
+            // Interval arithmetic only makes sense on date/time columns.
+            if is_datetime_field(field) {
+                update_functions.push(quote! {
+                  pub fn #update_col_with_interval_add(self, amount: i64, unit: ::prkorm::Unit) -> Self {
+                    let mut selected = self.selected.clone();
+                    selected.entry(#field_name_without_table.to_string()).or_insert(format!("{} + INTERVAL {} {}", #field_name_without_table, amount, unit));
+                    Self {
+                        selected: selected,
+                        ..self
+                    }
+                  }
+
+                  pub fn #update_col_with_interval_sub(self, amount: i64, unit: ::prkorm::Unit) -> Self {
+                    let mut selected = self.selected.clone();
+                    selected.entry(#field_name_without_table.to_string()).or_insert(format!("{} - INTERVAL {} {}", #field_name_without_table, amount, unit));
+                    Self {
+                        selected: selected,
+                        ..self
+                    }
+                  }
+                });
+            }
+        }
+
+        insert_functions.push(quote! {
+
+            pub fn #order_by_function(mut self, order : &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} {}",#field_name_with_table, order));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_asc_function(mut self) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} ASC",#field_name_with_table));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_desc_function(mut self) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} DESC",#field_name_with_table));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+        });
+
+        derived_functions.push(quote! {
+
+            #vis const #column_const: &'static str = #field_name_with_table;
+            
+            #vis fn #get_field_name_in_use<'a>() -> &'a str  {
+                (#field_name_with_table).as_ref()
+            }
+
+            #vis fn #column_of(alias: &str) -> String {
+                format!("{}.{}", alias, #field_name_without_table)
+            }
+        });
+
+        if !ops.select {
+            continue;
+        }
+
+        derived_functions.push(quote! {
+            #vis fn #select_field_name() -> #builder {
+              
+                Self::new_select_builder(format!("{}", #select_with_table))
+            }
+
+            #vis fn #select_function_over_field_name( function: &str ) -> #builder {
+                Self::new_select_builder(format!("{}({})", function.to_uppercase(),  #field_name_with_table))
+            }
+
+           
+
+            #vis fn #select_function_over_field_name_as(mut self, function: &str , alias: &str ) -> #builder {
+                Self::new_select_builder(format!("{}({}) AS {}", function.to_uppercase(),  #field_name_with_table, alias))
+            }
+        });
+
+        if is_integer_type(&field.ty) {
+            let where_has_flag = Ident::new(&format!("where_{}_has_flag", field_name), field_name.span());
+            let where_bitand = Ident::new(&format!("where_{}_bitand", field_name), field_name.span());
+            field_functions.push(quote! {
+                pub fn #where_has_flag(self, flag: u64) -> Self {
+                    self.#where_bitand(flag, "=", flag)
+                }
+
+                pub fn #where_bitand(mut self, mask: u64, operator: &str, value: u64) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("{} & {} {} {}", self.column(#field_name_without_table), mask, operator, value));
+                    Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    }
+                }
+            });
+        }
+
+        let where_older_than_interval = Ident::new(&format!("where_{}_older_than_interval", field_name), field_name.span());
+        let where_within_interval = Ident::new(&format!("where_{}_within_interval", field_name), field_name.span());
+        if is_datetime_field(field) {
+            field_functions.push(quote! {
+                pub fn #where_older_than_interval(mut self, amount: i64, unit: ::prkorm::Unit) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("{} < NOW() - INTERVAL {} {}", self.column(#field_name_without_table), amount, unit));
+                    Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn #where_within_interval(mut self, amount: i64, unit: ::prkorm::Unit) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("{} >= NOW() - INTERVAL {} {}", self.column(#field_name_without_table), amount, unit));
+                    Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    }
+                }
+            });
+        }
+
+        let find_by_function = Ident::new(&format!("find_by_{}", field_name), field_name.span());
+        let exists_by_function = Ident::new(&format!("exists_by_{}", field_name), field_name.span());
+        let count_by_function = Ident::new(&format!("count_by_{}", field_name), field_name.span());
+        derived_functions.push(quote! {
+            #vis fn #find_by_function(value: impl #value_bound) -> #builder {
+                Self::select().#where_function_name(value).limit(1)
+            }
+
+            #vis fn #exists_by_function(value: impl #value_bound) -> String {
+                let query = Self::select_str("1").#where_function_name(value);
+                query.finish(|this| format!("SELECT EXISTS({})", this.render(true)))
+            }
+
+            #vis fn #count_by_function(value: impl #value_bound) -> String {
+                Self::select().#where_function_name(value).count()
+            }
+        });
+
+        for aggregate in ["sum", "avg", "min", "max"] {
+            let select_aggregate = Ident::new(&format!("select_{}_of_{}", aggregate, field_name), field_name.span());
+            let select_aggregate_as = Ident::new(&format!("select_{}_of_{}_as", aggregate, field_name), field_name.span());
+            let function = aggregate.to_uppercase();
+            derived_functions.push(quote! {
+                #vis fn #select_aggregate() -> #builder {
+                    Self::new_select_builder(format!("{}({})", #function, #field_name_with_table))
+                }
+
+                #vis fn #select_aggregate_as(alias: &str) -> #builder {
+                    Self::new_select_builder(format!("{}({}) AS {}", #function, #field_name_with_table, alias))
+                }
+            });
+        }
+
+        if *field_name == primary_key_var {
+            // A key marked on its field is looked up with a value of the field's type.
+            let key_type = match primary_key_fields.is_empty() {
+                true => quote!(impl #value_bound),
+                false => {
+                    let ty = option_inner(&field.ty).unwrap_or(&field.ty);
+                    quote!(#ty)
+                }
+            };
+            derived_functions.push(quote! {
+                #vis fn find_by_primary_key(value: #key_type) -> #builder {
+                    Self::select().#where_function_name(value).limit(1)
+                }
+            });
+        }
+        
+        field_functions.push(quote! {
+
+            pub fn #select_field_name(mut self) -> Self {
+                Self {
+                    selected: format!("{}, {}{}", self.selected, #column_read, #select_suffix),
+                    default_selection: false,
+                    ..self
+                }
+            }
+            
+            pub fn #select_field_name_as(mut self, alias: &str) -> Self {
+                Self {
+                    selected: format!("{}, ({}) AS {}", self.selected, #column_read, alias),
+                    default_selection: false,
+                    ..self
+                }
+            }
+
+            pub fn #select_function_over_field_name(mut self, function: &str ) -> Self {
+                Self {
+                    selected: format!("{}, {}({})", self.selected, function.to_uppercase() ,self.column(#field_name_without_table) ),
+                    default_selection: false,
+                    ..self
+                }
+            }
+
+            pub fn #select_function_over_field_name_as(mut self, function: &str , alias: &str ) -> Self {
+                Self {
+                    selected: format!("{}, {}({}) AS {}", self.selected, function.to_uppercase() ,self.column(#field_name_without_table) , alias),
+                    default_selection: false,
+                    ..self
+                }
+            }
+
+
+            pub fn #inner_join(mut self, table: &str,  key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nINNER JOIN {} ON {}.{} = {}", table,table, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #join(mut self, table: &str,  key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nJOIN {} ON {}.{} = {}", table,table, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #left_join(mut self,  table: &str, key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nLEFT JOIN {} ON {}.{} = {}", table,table, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #inner_join_model_as<M: ::prkorm::Model>(mut self, alias: &str, key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nINNER JOIN {} {} ON {}.{} = {}", M::model_table(), alias, alias, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #left_join_model_as<M: ::prkorm::Model>(mut self, alias: &str, key: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nLEFT JOIN {} {} ON {}.{} = {}", M::model_table(), alias, alias, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #right_join(mut self, table: &str, key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nRIGHT JOIN {} ON {}.{} = {}", table,table, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #full_join(mut self,  table: &str, key: &str,) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.joins);
+                conditions.push(format!("\nFULL JOIN {} ON {}.{} = {}", table,table, key, self.column(#field_name_without_table)));
+                Self {
+                    joins: conditions.clone(),
+                    ..self
+                }
+            }
+            
+            pub fn #order_by_function(mut self, order : &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} {}",self.column(#field_name_without_table), order));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_asc_function(mut self) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} ASC",self.column(#field_name_without_table)));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_desc_function(mut self) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} DESC",self.column(#field_name_without_table)));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+            
+            pub fn #order_by_asc_nulls_first_function(mut self) -> Self {
+                let column = self.column(#field_name_without_table);
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("ISNULL({}) DESC, {} ASC", column, column));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_asc_nulls_last_function(mut self) -> Self {
+                let column = self.column(#field_name_without_table);
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("ISNULL({}), {} ASC", column, column));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_desc_nulls_first_function(mut self) -> Self {
+                let column = self.column(#field_name_without_table);
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("ISNULL({}) DESC, {} DESC", column, column));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_desc_nulls_last_function(mut self) -> Self {
+                let column = self.column(#field_name_without_table);
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("ISNULL({}), {} DESC", column, column));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_dir_function(mut self, order: ::prkorm::Order) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} {}",self.column(#field_name_without_table), order));
+                Self {
+                    order_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #order_by_collate_function(mut self, collation: &str, order: ::prkorm::Order) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.order_by);
+                conditions.push(format!("{} COLLATE {} {}", self.column(#field_name_without_table), collation, order));
+                Self {
+                    order_by: conditions.clone(),
+                    ..self
+                }
+            }
+
+            pub fn #where_eq_collate(mut self, value: impl #value_bound, collation: &str) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} = {} COLLATE {}", self.column(#field_name_without_table), #to_literal(&value), collation));
+                Self {
+                    where_conditions: conditions.clone(),
+                    ..self
+                }
+            }
+            
+            pub fn #group_by_function(mut self) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.group_by);
+                conditions.push(format!("{}",self.column(#field_name_without_table)));
+                Self {
+                    group_by: conditions.clone(), 
+                    ..self
+                }
+            }
+
+            pub fn #or_having_function(self, #field_name: impl #value_bound) -> Self {
+                let condition = format!("{} = {}", self.column(#field_name_without_table), #to_literal(&#field_name));
+                self.or_having_str(&condition)
+            }
+
+            pub fn #having_function(mut self, #field_name: impl #value_bound) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.having);
+                conditions.push(format!("{} = {}",self.column(#field_name_without_table), #to_literal(&#field_name) ));
+                Self {
+                    having: conditions.clone(), 
+                    ..self
+                }
+            }
+            pub fn #where_function_name_in(mut self, where_in: impl ToString) -> Self {
+                let where_in = where_in.to_string();
+                if where_in.trim().is_empty() {
+                    self.empty_in_lists.push(#field_name_without_table.to_string());
+                    return self;
+                }
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} IN ({})", self.column(#field_name_without_table),  where_in ));
+                Self {
+                    where_conditions: conditions.clone(), 
+                    ..self
+                }
+            }
+            pub fn #having_function_in(mut self, having_in: impl ToString) -> Self {
+                let having_in = having_in.to_string();
+                if having_in.trim().is_empty() {
+                    self.empty_having_in_lists.push(#field_name_without_table.to_string());
+                    return self;
+                }
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.having);
+                conditions.push(format!("{} IN ({})", self.column(#field_name_without_table), having_in));
+                Self {
+                    having: conditions.clone(),
+                    ..self
+                }
+            }
+            pub fn #where_any(mut self, operator: &str, subquery: impl ToString) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} {} ANY ({})", self.column(#field_name_without_table), operator, subquery.to_string()));
+                Self {
+                    where_conditions: conditions.clone(),
+                    ..self
+                }
+            }
+
+            pub fn #where_all(mut self, operator: &str, subquery: impl ToString) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} {} ALL ({})", self.column(#field_name_without_table), operator, subquery.to_string()));
+                Self {
+                    where_conditions: conditions.clone(),
+                    ..self
+                }
+            }
+
+            pub fn #where_gt_any(self, subquery: impl ToString) -> Self {
+                self.#where_any(">", subquery)
+            }
+
+            pub fn #where_lt_any(self, subquery: impl ToString) -> Self {
+                self.#where_any("<", subquery)
+            }
+
+            pub fn #where_gt_all(self, subquery: impl ToString) -> Self {
+                self.#where_all(">", subquery)
+            }
+
+            pub fn #where_lt_all(self, subquery: impl ToString) -> Self {
+                self.#where_all("<", subquery)
+            }
+
+            pub fn #where_function_name(mut self, #field_name:impl #value_bound) -> Self {
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} = {}",self.column(#field_name_without_table),  #to_literal(&#field_name) ));
+                Self {
+                    where_conditions: conditions.clone(), 
+                    ..self
+                }
+            }
+            pub fn #where_function_operator_name(mut self, operator: &str,  #field_name: impl #value_bound,) -> Self  {
+                // self.#field_name = update_with;
+                let mut conditions: Vec<String> = Vec::new();
+                conditions.append(&mut self.where_conditions);
+                conditions.push(format!("{} {} {}",self.column(#field_name_without_table), operator, #to_literal(&#field_name) ));
+                Self {
+                    where_conditions: conditions.clone(), 
+                    ..self
+                }
+            }
+        });
+    }
+
+
+
+    let delete_items = match ops.delete {
+        true => quote! {
+            #[derive(Clone, Default, PartialEq, Eq, Hash)]
+            #serde_derive
+            #vis struct #delete_builder {
+                table: String,
+                partitions: Vec<String>,
+                also_from: Vec<(String, String)>,
+                scope_conditions: Vec<String>,
+                tenant_column: Option<String>,
+                low_priority: bool,
+                quick: bool,
+                ignore: bool,
+                comments: std::collections::BTreeMap<String, String>,
+                naming: ::prkorm::TableNaming,
+                #serde_skip
+                observer: ::prkorm::ObserverSlot,
+            }
+
+            impl #delete_builder {
+                pub fn on_build(self, observer: fn(&str)) -> Self {
+                    Self {
+                        observer: ::prkorm::ObserverSlot::new(observer),
+                        ..self
+                    }
+                }
+
+                pub fn comment(mut self, key: &str, value: impl ToString) -> Self {
+                    self.comments.insert(key.to_string(), value.to_string());
+                    self
+                }
+
+                pub fn table_prefix(self, prefix: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_prefix(prefix),
+                        ..self
+                    }
+                }
+
+                pub fn in_schema(self, schema: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_schema(schema),
+                        ..self
+                    }
+                }
+
+                #shard_builder_function
+
+                pub fn scope(mut self, scope: &::prkorm::Scope) -> Self {
+                    assert!(
+                        scope.joins().is_empty() && scope.order_by().is_empty(),
+                        "scopes with joins or ORDER BY terms only apply to select builders"
+                    );
+                    self.scope_conditions.extend(scope.conditions().iter().map(|condition| format!("({})", condition)));
+                    self
+                }
+
+                fn finish(&self, render: impl FnOnce(&Self) -> String) -> String {
+                    let query = self.with_middleware();
+                    let sql = render(&query);
+                    let sql = match query.scope_conditions.is_empty() {
+                        true => sql,
+                        false => format!("{} AND {}", sql, query.scope_conditions.join(" AND ")),
+                    };
+                    let sql = ::prkorm::append_comments(sql, &query.comments);
+                    self.observer.notify(&sql);
+                    sql
+                }
+
+                fn with_middleware(&self) -> Self {
+                    let mut parts = ::prkorm::QueryParts {
+                        kind: ::prkorm::QueryKind::Delete,
+                        table: self.table.clone(),
+                        naming: self.naming.clone(),
+                        conditions: Vec::new(),
+                        comments: self.comments.clone(),
+                    };
+                    ::prkorm::run_query_middleware(&mut parts);
+                    let mut scope_conditions = self.scope_conditions.clone();
+                    scope_conditions.append(&mut parts.conditions);
+                    if let Some(column) = &self.tenant_column {
+                        match ::prkorm::tenant_condition(&self.table, column) {
+                            Ok(condition) => scope_conditions.insert(0, condition),
+                            Err(error) => panic!("{}", error),
+                        }
+                    }
+                    Self {
+                        table: parts.table,
+                        naming: parts.naming,
+                        scope_conditions,
+                        comments: parts.comments,
+                        ..self.clone()
+                    }
+                }
+
+                pub fn from_partition(self, partitions: &[&str]) -> Self {
+                    Self {
+                        partitions: partitions.iter().map(|partition| partition.to_string()).collect(),
+                        ..self
+                    }
+                }
+
+                fn table_reference(&self) -> String {
+                    match self.partitions.is_empty() {
+                        true => self.naming.resolve(&self.table),
+                        false => format!("{} PARTITION ({})", self.naming.resolve(&self.table), self.partitions.join(", ")),
+                    }
+                }
+
+                pub fn also_from(self, table: &str) -> Self {
+                    self.also_from_as(table, table)
+                }
+
+                pub fn also_from_as(mut self, table: &str, alias: &str) -> Self {
+                    let mut tables: Vec<(String, String)> = Vec::new();
+                    tables.append(&mut self.also_from);
+                    tables.push((table.to_string(), alias.to_string()));
+                    Self {
+                        also_from: tables.clone(),
+                        ..self
+                    }
+                }
+
+                fn other_tables(&self) -> String {
+                    self.also_from
+                        .iter()
+                        .map(|(table, alias)| format!(", {}", self.naming.resolve_as(table, alias)))
+                        .collect()
+                }
+
+                pub fn low_priority(self) -> Self {
+                    Self {
+                        low_priority: true,
+                        ..self
+                    }
+                }
+
+                pub fn quick(self) -> Self {
+                    Self {
+                        quick: true,
+                        ..self
+                    }
+                }
+
+                pub fn ignore(self) -> Self {
+                    Self {
+                        ignore: true,
+                        ..self
+                    }
+                }
+
+                fn delete_target(&self) -> String {
+                    let target = match self.also_from.is_empty() {
+                        true => format!("FROM {}", self.table_reference()),
+                        false => format!("{} FROM {}{}", self.naming.resolve(&self.table), self.table_reference(), self.other_tables()),
+                    };
+                    let modifiers = [(self.low_priority, "LOW_PRIORITY "), (self.quick, "QUICK "), (self.ignore, "IGNORE ")];
+                    let modifiers: String = modifiers.iter().filter(|(on, _)| *on).map(|(_, keyword)| *keyword).collect();
+                    format!("{}{}", modifiers, target)
+                }
+
+                pub fn delete_where_str(mut self, raw: &str) -> String {
+                    self.finish(|this| {
+                        let raw = match this.scope_conditions.is_empty() {
+                            true => raw.to_string(),
+                            false => format!("({})", raw),
+                        };
+                        format!("DELETE {} WHERE {}", this.delete_target(), raw)
+                    })
+                } 
+
+                #(#delete_functions)*
+            }
+
+            impl std::fmt::Debug for #delete_builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    // Only the `delete_where_*` methods render an executable statement.
+                    f.debug_struct(stringify!(#delete_builder))
+                        .field("sql", &format!("DELETE {} WHERE <condition>", self.delete_target()))
+                        .finish()
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let update_items = match ops.update {
+        true => quote! {
+            #[derive(Clone, Default, PartialEq, Eq, Hash)]
+            #serde_derive
+            #vis struct #update_builder {
+                selected: std::collections::BTreeMap<String, String>,
+                table: String,
+                partitions: Vec<String>,
+                also_from: Vec<(String, String)>,
+                scope_conditions: Vec<String>,
+                tenant_column: Option<String>,
+                ignore: bool,
+                comments: std::collections::BTreeMap<String, String>,
+                naming: ::prkorm::TableNaming,
+                #serde_skip
+                observer: ::prkorm::ObserverSlot,
+            }
+
+            impl #update_builder {
+                pub fn on_build(self, observer: fn(&str)) -> Self {
+                    Self {
+                        observer: ::prkorm::ObserverSlot::new(observer),
+                        ..self
+                    }
+                }
+
+                pub fn comment(mut self, key: &str, value: impl ToString) -> Self {
+                    self.comments.insert(key.to_string(), value.to_string());
+                    self
+                }
+
+                pub fn table_prefix(self, prefix: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_prefix(prefix),
+                        ..self
+                    }
+                }
+
+                pub fn in_schema(self, schema: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_schema(schema),
+                        ..self
+                    }
+                }
+
+                #shard_builder_function
+
+                pub fn scope(mut self, scope: &::prkorm::Scope) -> Self {
+                    assert!(
+                        scope.joins().is_empty() && scope.order_by().is_empty(),
+                        "scopes with joins or ORDER BY terms only apply to select builders"
+                    );
+                    self.scope_conditions.extend(scope.conditions().iter().map(|condition| format!("({})", condition)));
+                    self
+                }
+
+                fn finish(&self, render: impl FnOnce(&Self) -> String) -> String {
+                    let query = self.with_middleware();
+                    let sql = render(&query);
+                    let sql = match query.scope_conditions.is_empty() {
+                        true => sql,
+                        false => format!("{} AND {}", sql, query.scope_conditions.join(" AND ")),
+                    };
+                    let sql = ::prkorm::append_comments(sql, &query.comments);
+                    self.observer.notify(&sql);
+                    sql
+                }
+
+                fn with_middleware(&self) -> Self {
+                    let mut parts = ::prkorm::QueryParts {
+                        kind: ::prkorm::QueryKind::Update,
+                        table: self.table.clone(),
+                        naming: self.naming.clone(),
+                        conditions: Vec::new(),
+                        comments: self.comments.clone(),
+                    };
+                    ::prkorm::run_query_middleware(&mut parts);
+                    let mut scope_conditions = self.scope_conditions.clone();
+                    scope_conditions.append(&mut parts.conditions);
+                    if let Some(column) = &self.tenant_column {
+                        match ::prkorm::tenant_condition(&self.table, column) {
+                            Ok(condition) => scope_conditions.insert(0, condition),
+                            Err(error) => panic!("{}", error),
+                        }
+                    }
+                    Self {
+                        table: parts.table,
+                        naming: parts.naming,
+                        scope_conditions,
+                        comments: parts.comments,
+                        ..self.clone()
+                    }
+                }
+
+                pub fn from_partition(self, partitions: &[&str]) -> Self {
+                    Self {
+                        partitions: partitions.iter().map(|partition| partition.to_string()).collect(),
+                        ..self
+                    }
+                }
+
+                fn table_reference(&self) -> String {
+                    match self.partitions.is_empty() {
+                        true => self.naming.resolve(&self.table),
+                        false => format!("{} PARTITION ({})", self.naming.resolve(&self.table), self.partitions.join(", ")),
+                    }
+                }
+
+                pub fn also_from(self, table: &str) -> Self {
+                    self.also_from_as(table, table)
+                }
+
+                pub fn also_from_as(mut self, table: &str, alias: &str) -> Self {
+                    let mut tables: Vec<(String, String)> = Vec::new();
+                    tables.append(&mut self.also_from);
+                    tables.push((table.to_string(), alias.to_string()));
+                    Self {
+                        also_from: tables.clone(),
+                        ..self
+                    }
+                }
+
+                fn other_tables(&self) -> String {
+                    self.also_from
+                        .iter()
+                        .map(|(table, alias)| format!(", {}", self.naming.resolve_as(table, alias)))
+                        .collect()
+                }
+
+                pub fn ignore(self) -> Self {
+                    Self {
+                        ignore: true,
+                        ..self
+                    }
+                }
+
+                fn update_target(&self) -> String {
+                    let modifier = if self.ignore { "IGNORE " } else { "" };
+                    format!("{}{}{}", modifier, self.table_reference(), self.other_tables())
+                }
+
+                fn set_values(&self) -> String {
+                    let mut set_values = String::new();
+                    for (i, (k, v)) in self.selected.iter().enumerate() {
+                        set_values = format!("{}{} = {}", set_values, k, v);
+                        if i + 1 != self.selected.len() {
+                            set_values = format!("{}, ", set_values);
+                        }
+                    }
+                    set_values
+                }
+
+                pub fn where_str(mut self, where_condition: &str) -> String {
+                    self.finish(|this| {
+                        let where_condition = match this.scope_conditions.is_empty() {
+                            true => where_condition.to_string(),
+                            false => format!("({})", where_condition),
+                        };
+                        format!("UPDATE {} SET {} WHERE {}", this.update_target(), this.set_values(), where_condition)
+                    })
+                }
+
+                #version_builder_function
+
+                #(#update_functions)*
+
+            }
+
+            impl std::fmt::Debug for #update_builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    // Only the `update_where_*` methods render an executable statement.
+                    f.debug_struct(stringify!(#update_builder))
+                        .field("sql", &format!("UPDATE {} SET {} WHERE <condition>", self.update_target(), self.set_values()))
+                        .finish()
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let insert_items = match ops.insert {
+        true => quote! {
+            #[derive(Clone, Default, PartialEq, Eq, Hash)]
+            #serde_derive
+            #vis struct #insert_builder {
+                selected: std::collections::BTreeMap<String, Vec<String>>,
+                table: String,
+                modifiers: Vec<::prkorm::InsertModifier>,
+                limit: Option<u32>,
+                order_by: Vec<String>,
+                comments: std::collections::BTreeMap<String, String>,
+                naming: ::prkorm::TableNaming,
+                #serde_skip
+                observer: ::prkorm::ObserverSlot,
+            }
+
+            impl  #insert_builder {
+                pub fn on_build(self, observer: fn(&str)) -> Self {
+                    Self {
+                        observer: ::prkorm::ObserverSlot::new(observer),
+                        ..self
+                    }
+                }
+
+                pub fn comment(mut self, key: &str, value: impl ToString) -> Self {
+                    self.comments.insert(key.to_string(), value.to_string());
+                    self
+                }
+
+                pub fn table_prefix(self, prefix: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_prefix(prefix),
+                        ..self
+                    }
+                }
+
+                pub fn in_schema(self, schema: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_schema(schema),
+                        ..self
+                    }
+                }
+
+                #shard_builder_function
+
+                fn finish(&self, render: impl FnOnce(&Self) -> String) -> String {
+                    let sql = self.finalize(render);
+                    // An insert without values renders nothing, so nothing was built.
+                    if !sql.is_empty() {
+                        self.observer.notify(&sql);
+                    }
+                    sql
+                }
+
+                // Like `finish`, without notifying observers, for `Debug`.
+                fn finalize(&self, render: impl FnOnce(&Self) -> String) -> String {
+                    let query = self.with_middleware();
+                    ::prkorm::append_comments(render(&query), &query.comments)
+                }
+
+                fn with_middleware(&self) -> Self {
+                    let mut parts = ::prkorm::QueryParts {
+                        kind: ::prkorm::QueryKind::Insert,
+                        table: self.table.clone(),
+                        naming: self.naming.clone(),
+                        conditions: Vec::new(),
+                        comments: self.comments.clone(),
+                    };
+                    ::prkorm::run_query_middleware(&mut parts);
+                    Self {
+                        table: parts.table,
+                        naming: parts.naming,
+                        comments: parts.comments,
+                        ..self.clone()
+                    }
+                }
+
+                pub fn limit(mut self, limit: u32) -> Self {
+                    Self {
+                        limit: Some(limit), 
+                        ..self
+                    }
+                }
+
+                pub fn modifier(mut self, modifier: ::prkorm::InsertModifier) -> Self {
+                    if modifier.is_priority() {
+                        self.modifiers.retain(|existing| !existing.is_priority());
+                    }
+                    if !self.modifiers.contains(&modifier) {
+                        self.modifiers.push(modifier);
+                        self.modifiers.sort();
+                    }
+                    self
+                }
+
+                fn insert_keyword(&self) -> String {
+                    let mut keyword = String::from("INSERT");
+                    for modifier in &self.modifiers {
+                        keyword = format!("{} {}", keyword, modifier);
+                    }
+                    keyword
+                }
+
+                #(#insert_functions)*
+
+                pub fn build(self) -> String {
+                    self.finish(|this| this.render(false))
+                }
+
+                pub fn build_compact(&self) -> String {
+                    self.finish(|this| this.render(true))
+                }
+
+                pub fn build_terminated(self) -> String {
+                    format!("{};", self.build().trim_end())
+                }
+
+                pub fn build_normalized(&self) -> String {
+                    self.finish(|this| ::prkorm::normalize_sql(&this.render(true)))
+                }
+
+                pub fn build_prepared(&self, name: &str) -> String {
+                    self.finish(|this| ::prkorm::prepare_script(name, &this.render(true)))
+                }
+
+                fn render(&self, compact: bool) -> String {
+                    if self.selected.is_empty() {
+                        return String::new();
+                    }
+                    let mut keys = String::new();
+                    let mut values = String::new();
+                    for (i, (k, v)) in self.selected.clone().into_iter().enumerate() {
+                        keys = format!("{}{}", keys, k.clone());
+                        if (i + 1 != self.selected.len()) {
+                            keys = format!("{}, ", keys);
+                        }
+                    }
+                             let mut inputs = Vec::new();
+                     let mut results = Vec::new();
+
+                     for (k, v) in self.selected.clone().into_iter() {
+                     inputs.push(v);
+                     }
+                     for i in 0..inputs.first().unwrap().len() {
+                     let mut data = Vec::new();
+                     for j in 0..inputs.len() {
+                         data.push(inputs[j][i].clone());
+                     }
+                     results.push(data);
+                     }
+                     for i in 0..results.len() {
+                     let item = results[i].clone();
+                     let mut value = String::new();
+                     for j in 0..item.len() {
+                value = format!("{}{}", value, item[j]);
+                if j + 1 != item.len() {
+                    value = format!("{}, ", value);
+                         }
+                     }
+                        values = format!("{} ({})", values, value);
+                        if i + 1 != results.len() {
+                values = format!("{},", values);
+                        }
+                        }
+                    match compact {
+                        true => format!("{} INTO {} ({}) VALUES {}", self.insert_keyword(), self.naming.resolve(&self.table), keys, values.trim_start()),
+                        false => format!("{} INTO {}\n({}) VALUES {}", self.insert_keyword(), self.naming.resolve(&self.table), keys, values)
+                    }
+                }
+
+
+
+            }
+
+            impl std::fmt::Display for #insert_builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.finish(|this| this.render(false)))
+                }
+            }
+
+            impl std::fmt::Debug for #insert_builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    // Nothing can be rendered before a value is set.
+                    let sql = match self.selected.is_empty() {
+                        true => format!("INSERT INTO {}", self.naming.resolve(&self.table)),
+                        false => self.finalize(|this| this.render(false)),
+                    };
+                    f.debug_struct(stringify!(#insert_builder))
+                        .field("sql", &sql)
+                        .finish()
+                }
+            }
+
+            impl ::prkorm::Query for #insert_builder {
+                fn table_name(&self) -> &str {
+                    &self.table
+                }
+
+                fn build(&self) -> String {
+                    self.clone().build()
+                }
+            }
+
+            impl ::prkorm::WriteQuery for #insert_builder {}
+
+            impl From<#insert_builder> for String {
+                fn from(builder: #insert_builder) -> String {
+                    builder.build()
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let select_items = match ops.select {
+        true => quote! {
+            #[derive(Clone, PartialEq, Eq, Hash)]
+            #serde_derive
+            #vis struct #builder {
+                selected: String,
+                // Whether `selected` is still `DEFAULT_SELECTED`, letting `render`
+                // start from `SELECT_PREFIX` without comparing the projection.
+                default_selection: bool,
+                joins: Vec<String>,
+                primary_key: String,
+                table: String,
+                partitions: Vec<String>,
+                also_from: Vec<(String, String)>,
+                index_hints: Vec<String>,
+                table_alias: String,
+                limit: Option<u32>,
+                offset: Option<u32>,
+                max_execution_time: Option<u32>,
+                modifiers: Vec<::prkorm::SelectModifier>,
+                into_variables: Vec<String>,
+                where_conditions: Vec<String>,
+                group_by: Vec<String>,
+                group_aliases: Vec<String>,
+                order_by: Vec<String>,
+                order_aliases: Vec<String>,
+                having: Vec<String>,
+                empty_in: ::prkorm::EmptyIn,
+                empty_in_lists: Vec<String>,
+                empty_having_in_lists: Vec<String>,
+                tenant_column: Option<String>,
+                comments: std::collections::BTreeMap<String, String>,
+                naming: ::prkorm::TableNaming,
+                #serde_skip
+                observer: ::prkorm::ObserverSlot,
+            }
+
+            impl #builder {
+
+                pub fn empty_in(self, empty_in: ::prkorm::EmptyIn) -> Self {
+                    Self {
+                        empty_in,
+                        ..self
+                    }
+                }
+
+                pub fn join_str(mut self, join: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.joins);
+                    conditions.push(format!("\n{}", join));
+                    Self {
+                        joins: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn join_on(self, table: &str, on: ::prkorm::Expr) -> Self {
+                    self.join_str(&format!("JOIN {} ON {}", table, on))
+                }
+
+                pub fn left_join_on(self, table: &str, on: ::prkorm::Expr) -> Self {
+                    self.join_str(&format!("LEFT JOIN {} ON {}", table, on))
+                }
+
+                pub fn join_subquery(self, subquery: &str, on: &str) -> Self {
+                    self.join_str(&format!("JOIN {} ON {}", subquery, on))
+                }
+
+                pub fn left_join_subquery(self, subquery: &str, on: &str) -> Self {
+                    self.join_str(&format!("LEFT JOIN {} ON {}", subquery, on))
+                }
+
+                pub fn join_json_table(self, expr: &str, path: &str, columns: &[(&str, &str, &str)], alias: &str) -> Self {
+                    let columns: Vec<String> = columns
+                        .iter()
+                        .map(|(name, sql_type, column_path)| format!("{} {} PATH {}", name, sql_type, ::prkorm::quote_literal(column_path)))
+                        .collect();
+                    self.join_str(&format!("CROSS JOIN JSON_TABLE({}, {} COLUMNS ({})) AS {}", expr, ::prkorm::quote_literal(path), columns.join(", "), alias))
+                }
+
+                pub fn as_subquery(&self, alias: &str) -> String {
+                    format!("({}) AS {}", self.finish(|this| this.render(true)), alias)
+                }
+
+                pub fn having_str(mut self, having: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                        conditions.append(&mut self.having);
+                        conditions.push(format!("{}", having ));
+                        Self {
+                            
+                            having: conditions.clone(), 
+                            ..self
+                        }
+                }
+                pub fn where_str(mut self, where_query: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                        conditions.append(&mut self.where_conditions);
+                        conditions.push(format!("{}", where_query ));
+                        Self {
+                            
+                            where_conditions: conditions.clone(), 
+                            ..self
+                        }
+                }
+                pub fn where_expr(mut self, condition: ::prkorm::Expr) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(condition.to_string());
+                    Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn where_tuple_in<T: ::prkorm::RowValue>(mut self, columns: &[&str], rows: &[T]) -> Self {
+                    let columns: Vec<String> = columns
+                        .iter()
+                        .map(|column| match column.contains('.') {
+                            true => column.to_string(),
+                            false => self.column(column),
+                        })
+                        .collect();
+                    if rows.is_empty() {
+                        self.empty_in_lists.push(format!("({})", columns.join(", ")));
+                        return self;
+                    }
+                    let rows: Vec<String> = rows
+                        .iter()
+                        .map(|row| format!("({})", row.sql_literals().join(", ")))
+                        .collect();
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("({}) IN ({})", columns.join(", "), rows.join(", ")));
+                    Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn having_expr(mut self, condition: ::prkorm::Expr) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.having);
+                    conditions.push(condition.to_string());
+                    Self {
+                        having: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn or_having_str(mut self, having: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.having);
+                    match conditions.pop() {
+                        Some(previous) => conditions.push(format!("({} OR {})", previous, having)),
+                        None => conditions.push(having.to_string()),
+                    }
+                    Self {
+                        having: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn or_having_expr(self, condition: ::prkorm::Expr) -> Self {
+                    self.or_having_str(&condition.to_string())
+                }
+
+                pub fn group_by_str(mut self, group_by: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                        conditions.append(&mut self.group_by);
+                        conditions.push(format!("{}", group_by ));
+                        Self {
+                            
+                            group_by: conditions.clone(), 
+                            ..self
+                        }
+                }
+
+                pub fn group_by(mut self, expressions: &[&str]) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.group_by);
+                    conditions.extend(expressions.iter().map(|expression| expression.to_string()));
+                    Self {
+                        group_by: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn group_by_expr(mut self, expression: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.group_by);
+                    conditions.push(expression.trim().to_string());
+                    Self {
+                        group_by: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn group_by_alias(mut self, alias: &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.group_by);
+                    conditions.push(alias.to_string());
+                    self.group_aliases.push(alias.to_string());
+                    Self {
+                        group_by: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn order_by_str(mut self, order : &str) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.order_by);
+                    conditions.push(format!("{}", order));
+                    Self {
+                        order_by: conditions.clone(), 
+                        ..self
+                    }
+                }
+
+                pub fn order_by_random(mut self) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.order_by);
+                    conditions.push(String::from("RAND()"));
+                    Self {
+                        order_by: conditions.clone(), 
+                        ..self
+                    }
+                }
+
+                pub fn select_function_as(mut self, function: &str , over: &str , alias: &str ) -> Self {
+                    Self {
+                        selected: format!("{}, {}({}) AS {}", self.selected, function.to_uppercase() ,over, alias),
+                        default_selection: false,
+                        ..self
+                    }
+                }
+
+                pub fn select_window_as(mut self, function: &str, window: &::prkorm::Window, alias: &str) -> Self {
+                    Self {
+                        selected: format!("{}, {} OVER ({}) AS {}", self.selected, function, window, alias),
+                        default_selection: false,
+                        ..self
+                    }
+                }
+
+                pub fn select_as(mut self, selection: &str, alias: &str) -> Self {
+                    Self {
+                        selected: format!("{}, ({}) AS {}", self.selected, selection, alias),
+                        default_selection: false,
+                        ..self
+                    }
+                }
+
+                pub fn select_str(mut self, select: &str) -> Self {
+                    Self {
+                        selected: format!("{}, {}", self.selected, select),
+                        default_selection: false,
+                        ..self
+                    }
+                }
+
+                pub fn select_joined(self, table: &str, columns: &[&str]) -> Self {
+                    let name = table.rsplit('.').next().unwrap_or(table);
+                    self.select_joined_prefixed(table, columns, &format!("{}_", name))
+                }
+
+                pub fn select_model_as<M: ::prkorm::Model>(self, alias: &str) -> Self {
+                    self.select_joined(alias, M::model_columns())
+                }
+
+                pub fn select_joined_prefixed(self, table: &str, columns: &[&str], prefix: &str) -> Self {
+                    let columns: Vec<String> = columns
+                        .iter()
+                        .map(|column| format!("{}.{} AS {}{}", table, column, prefix, column.trim_matches('`')))
+                        .collect();
+                    match columns.is_empty() {
+                        true => self,
+                        false => self.select_str(&columns.join(", ")),
+                    }
+                }
+
+                pub fn limit(mut self, limit: u32) -> Self {
+                    Self {
+                        limit: Some(limit), 
+                        ..self
+                    }
+                }
+
+                pub fn from_partition(self, partitions: &[&str]) -> Self {
+                    Self {
+                        partitions: partitions.iter().map(|partition| partition.to_string()).collect(),
+                        ..self
+                    }
+                }
+
+                fn table_reference(&self) -> String {
+                    match self.partitions.is_empty() {
+                        true => self.naming.resolve(&self.table),
+                        false => format!("{} PARTITION ({})", self.naming.resolve(&self.table), self.partitions.join(", ")),
+                    }
+                }
+
+                pub fn also_from(self, table: &str) -> Self {
+                    self.also_from_as(table, table)
+                }
+
+                pub fn also_from_as(mut self, table: &str, alias: &str) -> Self {
+                    let mut tables: Vec<(String, String)> = Vec::new();
+                    tables.append(&mut self.also_from);
+                    tables.push((table.to_string(), alias.to_string()));
+                    Self {
+                        also_from: tables.clone(),
+                        ..self
+                    }
+                }
+
+                fn other_tables(&self) -> String {
+                    self.also_from
+                        .iter()
+                        .map(|(table, alias)| format!(", {}", self.naming.resolve_as(table, alias)))
+                        .collect()
+                }
+
+                pub fn use_index(self, indexes: &[&str]) -> Self {
+                    self.index_hint("USE", indexes)
+                }
+
+                pub fn force_index(self, indexes: &[&str]) -> Self {
+                    self.index_hint("FORCE", indexes)
+                }
+
+                pub fn ignore_index(self, indexes: &[&str]) -> Self {
+                    self.index_hint("IGNORE", indexes)
+                }
+
+                fn index_hint(mut self, kind: &str, indexes: &[&str]) -> Self {
+                    let mut index_hints: Vec<String> = Vec::new();
+                    index_hints.append(&mut self.index_hints);
+                    index_hints.push(format!("{} INDEX ({})", kind, indexes.join(", ")));
+                    Self {
+                        index_hints: index_hints.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn offset(mut self, offset: u32) -> Self {
+                    Self {
+                        offset: Some(offset), 
+                        ..self
+                    }
+                }
+
+                pub fn alias(self, alias: &str) -> Self {
+                    let requalify = |fragment: &String| ::prkorm::requalify(fragment, &self.table_alias, alias);
+                    Self {
+                        selected: requalify(&self.selected),
+                        default_selection: false,
+                        joins: self.joins.iter().map(requalify).collect(),
+                        where_conditions: self.where_conditions.iter().map(requalify).collect(),
+                        group_by: self.group_by.iter().map(requalify).collect(),
+                        order_by: self.order_by.iter().map(requalify).collect(),
+                        having: self.having.iter().map(requalify).collect(),
+                        table_alias: alias.to_string(),
+                        ..self
+                    }
+                }
+
+                fn column(&self, name: &str) -> String {
+                    format!("{}.{}", self.table_alias, name)
+                }
+
+                pub fn where_eq(mut self, column: #column_enum, value: impl ToString) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("{} = {}", self.column(column.name()), ::prkorm::literal(&value)));
+                    Self {
+                        where_conditions: conditions.clone(), 
+                        ..self
+                    }
+                }
+
+                pub fn group_by_column(mut self, column: #column_enum) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.group_by);
+                    conditions.push(self.column(column.name()));
+                    Self {
+                        group_by: conditions.clone(), 
+                        ..self
+                    }
+                }
+
+                pub fn group_by_columns(mut self, columns: &[#column_enum]) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.group_by);
+                    conditions.extend(columns.iter().map(|column| self.column(column.name())));
+                    Self {
+                        group_by: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn seek_after(self, columns: &[#column_enum], token: &str) -> Result<Self, ::prkorm::CursorError> {
+                    self.seek(">", columns, token)
+                }
+
+                pub fn seek_before(self, columns: &[#column_enum], token: &str) -> Result<Self, ::prkorm::CursorError> {
+                    self.seek("<", columns, token)
+                }
+
+                fn seek(mut self, operator: &str, columns: &[#column_enum], token: &str) -> Result<Self, ::prkorm::CursorError> {
+                    let cursor = ::prkorm::Cursor::decode(token)?;
+                    if cursor.values().len() != columns.len() {
+                        return Err(::prkorm::CursorError::ColumnCountMismatch {
+                            expected: columns.len(),
+                            found: cursor.values().len(),
+                        });
+                    }
+                    let names: Vec<String> = columns.iter().map(|column| self.column(column.name())).collect();
+                    let values: Vec<String> = cursor.values().iter().map(|value| ::prkorm::quote_literal(value)).collect();
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.where_conditions);
+                    conditions.push(format!("({}) {} ({})", names.join(", "), operator, values.join(", ")));
+                    Ok(Self {
+                        where_conditions: conditions.clone(),
+                        ..self
+                    })
+                }
+
+                pub fn order_by_column(mut self, column: #column_enum, order: ::prkorm::Order) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.order_by);
+                    conditions.push(format!("{} {}", self.column(column.name()), order));
+                    Self {
+                        order_by: conditions.clone(), 
+                        ..self
+                    }
+                }
+
+                // Columns that filters built from user input may name. `#[never_select]`
+                // columns are left out: matching on a secret would reveal it.
+                fn filter_column(name: &str) -> Result<#column_enum, ::prkorm::FilterError> {
+                    let never_selected: &[&str] = &[#(#never_selected_names),*];
+                    #column_enum::from_name(name)
+                        .filter(|_| !never_selected.contains(&name))
+                        .ok_or_else(|| ::prkorm::FilterError::UnknownColumn(name.to_string()))
+                }
+
+                pub fn apply_filter(mut self, filter: &::prkorm::Filter) -> Result<Self, ::prkorm::FilterError> {
+                    for (name, op) in &filter.conditions {
+                        let column = Self::filter_column(name)?;
+                        match op {
+                            ::prkorm::FilterOp::In(values) if values.is_empty() => {
+                                self.empty_in_lists.push(column.name().to_string())
+                            }
+                            _ => self.where_conditions.push(op.render(&self.column(column.name()))),
+                        }
+                    }
+                    for (name, order) in &filter.order_by {
+                        let column = Self::filter_column(name)?;
+                        self.order_by.push(format!("{} {}", self.column(column.name()), order));
+                    }
+                    if filter.limit.is_some() {
+                        self.limit = filter.limit;
+                    }
+                    if filter.offset.is_some() {
+                        self.offset = filter.offset;
+                    }
+                    Ok(self)
+                }
+
+                pub fn order_by_param(self, field: &str, direction: &str) -> Result<Self, ::prkorm::FilterError> {
+                    let order = ::prkorm::Order::parse(direction)
+                        .ok_or_else(|| ::prkorm::FilterError::InvalidDirection(direction.to_string()))?;
+                    let mut filter = ::prkorm::Filter::new();
+                    filter.order_by.push((field.to_string(), order));
+                    self.apply_filter(&filter)
+                }
+
+                pub fn where_from_map(self, filters: &std::collections::HashMap<String, String>) -> Result<Self, ::prkorm::FilterError> {
+                    let mut filter = ::prkorm::Filter::new();
+                    for (column, value) in filters {
+                        filter.conditions.push((column.clone(), ::prkorm::FilterOp::Eq(value.clone())));
+                    }
+                    // HashMap order is random; keep the rendered SQL stable.
+                    filter.conditions.sort_by(|a, b| a.0.cmp(&b.0));
+                    self.apply_filter(&filter)
+                }
+
+                pub fn where_from_map_ops(self, filters: &std::collections::HashMap<String, (String, String)>) -> Result<Self, ::prkorm::FilterError> {
+                    let mut filter = ::prkorm::Filter::new();
+                    for (column, (operator, value)) in filters {
+                        filter.conditions.push((column.clone(), ::prkorm::FilterOp::parse(operator, value)?));
+                    }
+                    filter.conditions.sort_by(|a, b| a.0.cmp(&b.0));
+                    self.apply_filter(&filter)
+                }
+
+                pub fn scope(mut self, scope: &::prkorm::Scope) -> Self {
+                    for join in scope.joins() {
+                        let join = format!("\n{}", join);
+                        if !self.joins.contains(&join) {
+                            self.joins.push(join);
+                        }
+                    }
+                    for condition in scope.conditions() {
+                        let condition = format!("({})", condition);
+                        if !self.where_conditions.contains(&condition) {
+                            self.where_conditions.push(condition);
+                        }
+                    }
+                    for order in scope.order_by() {
+                        if !self.order_by.contains(order) {
+                            self.order_by.push(order.clone());
+                        }
+                    }
+                    self
+                }
+
+                pub fn merge_filters_from(mut self, other: &Self) -> Self {
+                    for join in &other.joins {
+                        if !self.joins.contains(join) {
+                            self.joins.push(join.clone());
+                        }
+                    }
+                    for condition in &other.where_conditions {
+                        if !self.where_conditions.contains(condition) {
+                            self.where_conditions.push(condition.clone());
+                        }
+                    }
+                    for column in &other.empty_in_lists {
+                        self.empty_in_lists.push(column.clone());
+                    }
+                    for alias in &other.order_aliases {
+                        if !self.order_aliases.contains(alias) {
+                            self.order_aliases.push(alias.clone());
+                        }
+                    }
+                    for order in &other.order_by {
+                        if !self.order_by.contains(order) {
+                            self.order_by.push(order.clone());
+                        }
+                    }
+                    self
+                }
+
+                pub fn clear_where(self) -> Self {
+                    Self {
+                        where_conditions: Vec::new(),
+                        empty_in_lists: Vec::new(),
+                        ..self
+                    }
+                }
+
+                pub fn order_by_alias(mut self, alias: &str, order: ::prkorm::Order) -> Self {
+                    let mut conditions: Vec<String> = Vec::new();
+                    conditions.append(&mut self.order_by);
+                    conditions.push(format!("{} {}", alias, order));
+                    self.order_aliases.push(alias.to_string());
+                    Self {
+                        order_by: conditions.clone(),
+                        ..self
+                    }
+                }
+
+                pub fn clear_order_by(self) -> Self {
+                    Self {
+                        order_by: Vec::new(),
+                        order_aliases: Vec::new(),
+                        ..self
+                    }
+                }
+
+                pub fn clear_group_by(self) -> Self {
+                    Self {
+                        group_by: Vec::new(),
+                        group_aliases: Vec::new(),
+                        ..self
+                    }
+                }
+
+                pub fn clear_having(self) -> Self {
+                    Self {
+                        having: Vec::new(),
+                        empty_having_in_lists: Vec::new(),
+                        ..self
+                    }
+                }
+
+                pub fn clear_joins(self) -> Self {
+                    Self {
+                        joins: Vec::new(),
+                        ..self
+                    }
+                }
+
+                pub fn max_execution_time(self, milliseconds: u32) -> Self {
+                    Self {
+                        max_execution_time: Some(milliseconds),
+                        ..self
+                    }
+                }
+
+                pub fn into_variables(self, variables: &[&str]) -> Self {
+                    Self {
+                        into_variables: variables.iter().map(|variable| variable.to_string()).collect(),
+                        ..self
+                    }
+                }
+
+                pub fn modifier(mut self, modifier: ::prkorm::SelectModifier) -> Self {
+                    if !self.modifiers.contains(&modifier) {
+                        self.modifiers.push(modifier);
+                        self.modifiers.sort();
+                    }
+                    self
+                }
+
+                fn select_keyword(&self) -> String {
+                    let mut keyword = match self.max_execution_time {
+                        Some(milliseconds) => format!("SELECT /*+ MAX_EXECUTION_TIME({}) */", milliseconds),
+                        None => String::from("SELECT"),
+                    };
+                    for modifier in &self.modifiers {
+                        keyword = format!("{} {}", keyword, modifier);
+                    }
+                    keyword
+                }
+
+                pub fn clear_limit(self) -> Self {
+                    Self {
+                        limit: None,
+                        offset: None,
+                        ..self
+                    }
+                }
+
+                pub fn on_build(self, observer: fn(&str)) -> Self {
+                    Self {
+                        observer: ::prkorm::ObserverSlot::new(observer),
+                        ..self
+                    }
+                }
+
+                pub fn comment(mut self, key: &str, value: impl ToString) -> Self {
+                    self.comments.insert(key.to_string(), value.to_string());
+                    self
+                }
+
+                pub fn table_prefix(self, prefix: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_prefix(prefix),
+                        ..self
+                    }
+                }
+
+                pub fn in_schema(self, schema: &str) -> Self {
+                    Self {
+                        naming: self.naming.clone().with_schema(schema),
+                        ..self
+                    }
+                }
+
+                #shard_builder_function
+
+                fn finish(&self, render: impl FnOnce(&Self) -> String) -> String {
+                    match self.try_finish(render) {
+                        Ok(sql) => sql,
+                        Err(error) => panic!("{}", error),
+                    }
+                }
+
+                fn try_finish(&self, render: impl FnOnce(&Self) -> String) -> Result<String, ::prkorm::QueryBuildError> {
+                    let sql = self.finalize(render)?;
+                    self.observer.notify(&sql);
+                    Ok(sql)
+                }
+
+                // Like `try_finish`, without notifying observers, for `Debug`.
+                fn finalize(&self, render: impl FnOnce(&Self) -> String) -> Result<String, ::prkorm::QueryBuildError> {
+                    let query = self.with_middleware()?;
+                    Ok(::prkorm::append_comments(render(&query), &query.comments))
+                }
+
+                fn with_middleware(&self) -> Result<Self, ::prkorm::QueryBuildError> {
+                    let mut parts = ::prkorm::QueryParts {
+                        kind: ::prkorm::QueryKind::Select,
+                        table: self.table.clone(),
+                        naming: self.naming.clone(),
+                        conditions: Vec::new(),
+                        comments: self.comments.clone(),
+                    };
+                    ::prkorm::run_query_middleware(&mut parts);
+                    let mut guards: Vec<String> = Vec::new();
+                    if let Some(column) = &self.tenant_column {
+                        guards.push(::prkorm::tenant_condition(&self.table, &self.column(column))?);
+                    }
+                    for condition in parts.conditions {
+                        if !self.where_conditions.contains(&condition) && !guards.contains(&condition) {
+                            guards.push(condition);
+                        }
+                    }
+                    // Group the caller's conditions so an OR in them cannot
+                    // reach past the tenant and middleware predicates.
+                    let where_conditions = match (guards.is_empty(), self.where_conditions.is_empty()) {
+                        (true, _) => self.where_conditions.clone(),
+                        (false, true) => guards,
+                        (false, false) => {
+                            let mut where_conditions = guards;
+                            where_conditions.insert(usize::from(self.tenant_column.is_some()), format!("({})", self.where_conditions.join(" AND ")));
+                            where_conditions
+                        }
+                    };
+                    Ok(Self {
+                        table: parts.table,
+                        naming: parts.naming,
+                        where_conditions,
+                        comments: parts.comments,
+                        ..self.clone()
+                    })
+                }
+
+                #(#field_functions)*
+
+
+                pub fn build(&self) -> String {
+                    self.finish(|this| this.render(false))
+                }
+
+                pub fn build_compact(&self) -> String {
+                    self.finish(|this| this.render(true))
+                }
+
+                pub fn build_terminated(&self) -> String {
+                    format!("{};", self.build().trim_end())
+                }
+
+                pub fn build_normalized(&self) -> String {
+                    let mut query = self.clone();
+                    query.where_conditions.sort();
+                    query.where_conditions.dedup();
+                    query.having.sort();
+                    query.finish(|this| ::prkorm::normalize_sql(&this.render(true)))
+                }
+
+                pub fn build_prepared(&self, name: &str) -> String {
+                    self.finish(|this| ::prkorm::prepare_script(name, &this.render(true)))
+                }
+
+                pub fn build_paged(&self, page: u32, per_page: u32) -> ::prkorm::PagedQuery {
+                    let rows = Self {
+                        limit: Some(per_page),
+                        offset: Some(page.max(1).saturating_sub(1).saturating_mul(per_page)),
+                        ..self.clone()
+                    };
+                    ::prkorm::PagedQuery {
+                        rows: rows.build(),
+                        count: self.finish(Self::render_count),
+                        page: page.max(1),
+                        per_page,
+                    }
+                }
+
+                pub fn count(&self) -> String {
+                self.finish(Self::render_count)
+            }
+
+            fn render_count(&self) -> String {
+                    let query = Self {
+                        order_by: Vec::new(),
+                        order_aliases: Vec::new(),
+                        limit: None,
+                        offset: None,
+                        into_variables: Vec::new(),
+                        ..self.clone()
+                    };
+                    if query.group_by.is_empty() && query.having.is_empty() {
+                        return Self { selected: String::from("COUNT(*)"), default_selection: false, ..query }.render(false);
+                    }
+                    let select = query.select_keyword();
+                    let query = Self { max_execution_time: None, ..query };
+                    format!("{} COUNT(*) \nFROM ({}) AS counted", select, query.render(false))
+                }
+
+                pub fn try_build(&self) -> Result<String, ::prkorm::QueryBuildError> {
+                    if let Some(error) = self.validate().into_iter().next() {
+                        return Err(error);
+                    }
+                    match self.into_variables.is_empty() && self.modifiers.is_empty() {
+                        true => ::prkorm::check_syntax(&self.render(false))?,
+                        // sqlparser knows neither `INTO @variable` nor the select
+                        // modifiers, so check the query without them.
+                        false => ::prkorm::check_syntax(&Self { into_variables: Vec::new(), modifiers: Vec::new(), ..self.clone() }.render(false))?,
+                    }
+                    self.try_finish(|this| this.render(false))
+                }
+
+                pub fn validate(&self) -> Vec<::prkorm::QueryBuildError> {
+                    let mut errors = Vec::new();
+                    if !self.having.is_empty()
+                        && self.group_by.is_empty()
+                        && !::prkorm::contains_aggregate(&self.selected)
+                        && !self.having.iter().any(|having| ::prkorm::contains_aggregate(having))
+                    {
+                        errors.push(::prkorm::QueryBuildError::HavingWithoutGroupBy);
+                    }
+                    for (i, condition) in self.where_conditions.iter().enumerate() {
+                        if self.where_conditions[..i].contains(condition) {
+                            errors.push(::prkorm::QueryBuildError::DuplicateCondition(condition.clone()));
+                        }
+                    }
+                    if self.offset.is_some() && self.order_by.is_empty() {
+                        errors.push(::prkorm::QueryBuildError::UnorderedPagination);
+                    }
+                    if self.offset.is_some() && self.limit.is_none() {
+                        errors.push(::prkorm::QueryBuildError::OffsetWithoutLimit);
+                    }
+                    if self.selected.trim().is_empty() {
+                        errors.push(::prkorm::QueryBuildError::EmptyProjection);
+                    }
+                    let column_aliases = ::prkorm::aliases(&self.selected);
+                    for expression in &self.group_by {
+                        if expression.is_empty() || ::prkorm::contains_aggregate(expression) {
+                            errors.push(::prkorm::QueryBuildError::InvalidGroupBy(expression.clone()));
+                        }
+                    }
+                    for alias in self.group_aliases.iter().chain(&self.order_aliases) {
+                        if !column_aliases.iter().any(|column_alias| column_alias.eq_ignore_ascii_case(alias)) {
+                            errors.push(::prkorm::QueryBuildError::UnknownAlias(alias.clone()));
+                        }
+                    }
+                    let mut table_aliases = vec![self.table_alias.clone()];
+                    table_aliases.extend(self.also_from.iter().map(|(_, alias)| alias.clone()));
+                    table_aliases.extend(self.joins.iter().filter_map(|join| ::prkorm::join_alias(join)));
+                    for aliases in [column_aliases, table_aliases] {
+                        let mut seen: Vec<String> = Vec::new();
+                        for alias in aliases {
+                            let alias = alias.to_lowercase();
+                            if seen.contains(&alias) {
+                                errors.push(::prkorm::QueryBuildError::AliasCollision(alias.clone()));
+                            }
+                            seen.push(alias);
+                        }
+                    }
+                    for term in ::prkorm::unselected_order_terms(&self.selected, &self.order_by) {
+                        errors.push(::prkorm::QueryBuildError::OrderByNotSelected(term));
+                    }
+                    if self.empty_in == ::prkorm::EmptyIn::Error {
+                        for column in self.empty_in_lists.iter().chain(&self.empty_having_in_lists) {
+                            errors.push(::prkorm::QueryBuildError::EmptyInList(column.clone()));
+                        }
+                    }
+                    errors
+                }
+
+                fn render(&self, compact: bool) -> String {
+                    let br = if compact { " " } else { " \n" };
+                    let limit = match self.limit {
+                        Some(limit) => format!("{}LIMIT {}", br, limit), 
+                        None => String::new()
+                    };
+                    let limit = match self.offset {
+                        Some(offset) => format!("{}{}OFFSET {}", limit, br, offset), 
+                        None => limit
+                    };
+                    let limit = match self.into_variables.is_empty() {
+                        true => limit,
+                        false => format!("{}{}INTO {}", limit, br, self.into_variables.join(", ")),
+                    };
+                   
+                        let mut where_conditions = self.where_conditions.clone();
+                        if !self.empty_in_lists.is_empty() && self.empty_in != ::prkorm::EmptyIn::Skip {
+                            where_conditions.push(String::from("1 = 0"));
+                        }
+                        let mut where_query = String::new();
+                        for i in 0..where_conditions.len() {
+                            if(i ==0) {
+                                where_query = format!("{}WHERE", br);
+                            }
+                            where_query = format!("{} {}", where_query, where_conditions[i].clone());
+                            if (i + 1 != where_conditions.len()) {
+                                where_query = format!("{} {}", where_query, "AND");
+                            }
+                        }
+                        let mut joins = String::new();
+                        for i in 0..self.joins.len() {
+                            if compact {
+                                joins = format!("{} {}", joins, self.naming.resolve_join(self.joins[i].trim_start()));
+                                continue;
+                            }
+                            if(i ==0) {
+                                joins = format!(" ");
+                            }
+                            joins = format!("{} {} ", joins, self.naming.resolve_join(&self.joins[i]));
+                            
+                        }
+                        let mut group_by = String::new();
+                        for i in 0..self.group_by.len() {
+                            if(i ==0) {
+                                group_by = format!("{}GROUP BY", br);
+                            }
+                            group_by = format!("{} {}", group_by, self.group_by[i].clone());
+                            if (i + 1 != self.group_by.len()) {
+                                group_by = format!("{},", group_by);
+                            }
+                        }
+                        let mut order_by = String::new();
+                        for i in 0..self.order_by.len() {
+                            if(i ==0) {
+                                order_by = format!("{}ORDER BY", br);
+                            }
+                            order_by = format!("{} {}", order_by, self.order_by[i].clone());
+                            if (i + 1 != self.order_by.len()) {
+                                order_by = format!("{},", order_by);
+                            }
+                        }
+                        let mut having_conditions = self.having.clone();
+                        if !self.empty_having_in_lists.is_empty() && self.empty_in != ::prkorm::EmptyIn::Skip {
+                            having_conditions.push(String::from("1 = 0"));
+                        }
+                        let mut having = String::new();
+                        for i in 0..having_conditions.len() {
+                            if(i ==0) {
+                                having = format!("{}HAVING", br);
+                            }
+                            having = format!("{} {}", having, having_conditions[i].clone());
+                            if (i + 1 != having_conditions.len()) {
+                                having = format!("{} AND", having);
+                            }
+                        }
+                        let prefixed = !compact
+                            && self.default_selection
+                            && self.max_execution_time.is_none()
+                            && self.modifiers.is_empty()
+                            && self.partitions.is_empty()
+                            && self.index_hints.is_empty()
+                            && self.also_from.is_empty()
+                            && self.table == #table
+                            && self.naming.is_identity();
+                        if prefixed {
+                            return format!("{}{}{}{}{}{}{}", #struct_name::SELECT_PREFIX, joins, where_query, group_by, having, order_by, limit);
+                        }
+                        let this_table =  match &self.table_alias == &self.table && self.naming.resolve(&self.table) == self.table {
+                            true => "", 
+                            false => &self.table_alias
+                        };
+                        let mut from = match compact && this_table.is_empty() {
+                            true => self.table_reference(),
+                            false => format!("{} {}", self.table_reference(), this_table)
+                        };
+                        for hint in &self.index_hints {
+                            from = format!("{} {}", from.trim_end(), hint);
+                        }
+                        if !self.also_from.is_empty() {
+                            from = format!("{}{}", from.trim_end(), self.other_tables());
+                        }
+                        format!("{} {}{}FROM {}{}{}{}{}{}{}", self.select_keyword(), self.selected, br, from ,joins, where_query, group_by, having,order_by, limit)
+                }
+            }
+
+            impl std::fmt::Display for #builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    // Formatting never panics; `build()` reports a missing tenant.
+                    match self.try_finish(|this| this.render(false)) {
+                        Ok(sql) => write!(f, "{}", sql),
+                        Err(_) => f.write_str("<no tenant set>"),
+                    }
+                }
+            }
+
+            impl std::fmt::Debug for #builder {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let sql = match self.finalize(|this| this.render(false)) {
+                        Ok(sql) => sql,
+                        Err(_) => String::from("<no tenant set>"),
+                    };
+                    f.debug_struct(stringify!(#builder))
+                        .field("sql", &sql)
+                        .finish()
+                }
+            }
+
+            impl ::prkorm::Query for #builder {
+                fn table_name(&self) -> &str {
+                    &self.table
+                }
+
+                fn build(&self) -> String {
+                    #builder::build(self)
+                }
+            }
+
+            impl ::prkorm::SelectQuery for #builder {
+                fn table_alias(&self) -> &str {
+                    &self.table_alias
+                }
+
+                fn primary_key(&self) -> &str {
+                    &self.primary_key
+                }
+            }
+
+            impl From<#builder> for String {
+                fn from(builder: #builder) -> String {
+                    builder.build()
+                }
+            }
+        },
+        false => quote! {},
+    };
+    let delete_constructor = match ops.delete {
+        true => quote! {
+                #vis fn delete() -> #delete_builder {
+                    #delete_builder {
+                        table: #table.into(),
+                        naming: Self::table_naming(),
+                        tenant_column: #tenant_column,
+                        ..#delete_builder::default()
+                    }
+                }
+        },
+        false => quote! {},
+    };
+    let update_constructor = match ops.update {
+        true => quote! {
+                #vis fn update() -> #update_builder {
+                    #update_builder {
+                        table: #table.into(),
+                        naming: Self::table_naming(),
+                        tenant_column: #tenant_column,
+                        ..#update_builder::default()
+                    }
+                }
+        },
+        false => quote! {},
+    };
+    let insert_constructor = match ops.insert {
+        true => quote! {
+                #vis fn insert() -> #insert_builder {
+                    #insert_builder {
+                        table: #table.into(),
+                        naming: Self::table_naming(),
+                        ..#insert_builder::default()
+                    }
+                }
+
+                #vis fn upsert_increment(keys: &[(&str, &str)], counters: &[(&str, i64)]) -> String {
+                    let mut builder = Self::insert();
+                    for (column, value) in keys {
+                        builder.selected.insert(column.to_string(), vec![::prkorm::quote_literal(value)]);
+                    }
+                    for (column, delta) in counters {
+                        builder.selected.insert(column.to_string(), vec![delta.to_string()]);
+                    }
+                    let increments: Vec<String> = counters
+                        .iter()
+                        .map(|(column, _)| format!("{} = {} + VALUES({})", column, column, column))
+                        .collect();
+                    builder.finish(|this| format!("{} \nON DUPLICATE KEY UPDATE {}", this.render(false), increments.join(", ")))
+                }
+        },
+        false => quote! {},
+    };
+    let select_constructors = match ops.select {
+        true => quote! {
+                #vis const SELECT_PREFIX: &'static str = #select_prefix;
+
+                const DEFAULT_SELECTED: &'static str = #default_field_names;
+
+                fn new_select_builder(selected: String) -> #builder {
+                    #builder {
+                        primary_key: Self::table_primary_key(),
+                        limit: None,
+                        offset: None,
+                        max_execution_time: None,
+                        modifiers: Vec::new(),
+                        into_variables: Vec::new(),
+                        joins: Vec::new(),
+                        where_conditions: Vec::new(),
+                        group_by: Vec::new(),
+                        group_aliases: Vec::new(),
+                        order_by: Vec::new(),
+                        order_aliases: Vec::new(),
+                        having: Vec::new(),
+                        table: #table.into(),
+                        partitions: Vec::new(),
+                        also_from: Vec::new(),
+                        index_hints: Vec::new(),
+                        empty_in: ::prkorm::EmptyIn::default(),
+                        empty_in_lists: Vec::new(),
+                        empty_having_in_lists: Vec::new(),
+                        tenant_column: #tenant_column,
+                        table_alias: #table_as.into(),
+                        default_selection: selected == Self::DEFAULT_SELECTED,
+                        selected,
+                        comments: std::collections::BTreeMap::new(),
+                        naming: Self::table_naming(),
+                        observer: ::prkorm::ObserverSlot::default(),
+                    }
+                }
+
+                #vis fn select() -> #builder {
+                    Self::new_select_builder(String::from(Self::DEFAULT_SELECTED))#default_limit
+                }
+
+                #vis fn select_all_columns() -> #builder {
+                    Self::new_select_builder(format!("{}", #field_names))#default_limit
+                }
+
+                #vis fn select_function_over_field_name( function: &str, over: &str ) -> #builder {
+                    Self::new_select_builder(format!("{}({})", function.to_uppercase(),  over))
+                }
+                #vis fn select_function_over_field_name_as( function: &str, over: &str, alias: &str ) -> #builder {
+                    Self::new_select_builder(format!("{}({}) AS {}", function.to_uppercase(),  over, alias))
+                }
+
+                #vis fn select_str(select: &str) -> #builder {
+                    Self::new_select_builder(format!("{}", select))
+                }
+
+                #vis fn select_str_as(select: &str, alias: &str) -> #builder {
+                    Self::new_select_builder(format!("({}) AS {}", select, alias))
+                }
+        },
+        false => quote! {},
+    };
+
+    // Generate the struct and its associated functions.
+    let gen = quote! {
+        #delete_items
+
+        #update_items
+
+        #insert_items
+
+        #select_items
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #column_enum {
+            #(#column_variants),*
+        }
+
+        impl #column_enum {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(#column_qualified_arms),*
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(#column_name_arms),*
+                }
+            }
+
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#column_from_name_arms,)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::fmt::Display for #column_enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl #struct_name {
+            #delete_constructor
+
+            #update_constructor
+
+            #insert_constructor
+
+            #select_constructors
+
+            #vis fn query_template(sql: &str) -> ::prkorm::QueryTemplate {
+                ::prkorm::QueryTemplate::new(sql)
+            }
+
+            #(#derived_functions)*
+
+            #vis fn columns() -> &'static [&'static str] {
+                &[#(#column_names),*]
+            }
+
+            #vis fn qualified_columns() -> &'static [&'static str] {
+                &[#(#qualified_column_names),*]
+            }
+
+            #vis fn field_count() -> usize {
+                #field_count
+            }
+
+            #vis fn table() -> &'static str {
+                #table
+            } 
+            #vis fn table_name(&self) -> &'static str {
+                #table
+            }
+            
+            fn table_naming() -> ::prkorm::TableNaming {
+                #table_naming
+            }
+
+            #vis fn qualified_table() -> String {
+                Self::table_naming().resolve(#table)
+            }
+
+            #vis fn table_primary_key() -> String {
+                format!("{}", #primary_key_sql)
+            }
+
+            #vis fn lock_read() -> ::prkorm::LockTables {
+                ::prkorm::LockTables::new().read_model::<Self>()
+            }
+
+            #vis fn lock_write() -> ::prkorm::LockTables {
+                ::prkorm::LockTables::new().write_model::<Self>()
+            }
+
+            #vis fn get_lock_sql(name: &str, timeout: i32) -> String {
+                format!("SELECT GET_LOCK({}, {})", ::prkorm::quote_literal(name), timeout)
+            }
+
+            #vis fn release_lock_sql(name: &str) -> String {
+                format!("SELECT RELEASE_LOCK({})", ::prkorm::quote_literal(name))
+            }
+
+            #vis fn create_table_sql() -> String {
+                format!("CREATE TABLE {} (\n{}\n){}", Self::table_naming().resolve(#table), #column_definitions, #table_options)
+            }
+
+            #vis fn show_columns() -> String {
+                format!("SHOW COLUMNS FROM {}", Self::table_naming().resolve(#table))
+            }
+
+            #vis fn show_create_table() -> String {
+                format!("SHOW CREATE TABLE {}", Self::table_naming().resolve(#table))
+            }
+
+            #vis fn show_indexes() -> String {
+                format!("SHOW INDEXES FROM {}", Self::table_naming().resolve(#table))
+            }
+
+            #vis fn introspect_columns_query() -> String {
+                format!(
+                    "SELECT COLUMN_NAME, COLUMN_TYPE, IS_NULLABLE, COLUMN_DEFAULT, COLUMN_KEY, EXTRA \nFROM information_schema.COLUMNS \nWHERE {} \nORDER BY ORDINAL_POSITION",
+                    Self::table_naming().schema_filter(#table)
+                )
+            }
+
+            #vis fn introspect_table_size_query() -> String {
+                format!(
+                    "SELECT TABLE_ROWS, DATA_LENGTH, INDEX_LENGTH, DATA_LENGTH + INDEX_LENGTH AS TOTAL_LENGTH \nFROM information_schema.TABLES \nWHERE {}",
+                    Self::table_naming().schema_filter(#table)
+                )
+            }
+
+            #vis fn introspect_indexes_query() -> String {
+                format!(
+                    "SELECT INDEX_NAME, COLUMN_NAME, SEQ_IN_INDEX, NON_UNIQUE, CARDINALITY \nFROM information_schema.STATISTICS \nWHERE {} \nORDER BY INDEX_NAME, SEQ_IN_INDEX",
+                    Self::table_naming().schema_filter(#table)
+                )
+            }
+
+            #vis fn create_view_sql(name: &str, query: &impl ::prkorm::SelectQuery) -> String {
+                format!("CREATE VIEW {} AS {}", name, query.build())
+            }
+
+            #vis fn create_or_replace_view_sql(name: &str, query: &impl ::prkorm::SelectQuery) -> String {
+                format!("CREATE OR REPLACE VIEW {} AS {}", name, query.build())
+            }
+
+            #vis fn create_table_as(name: &str, query: &impl ::prkorm::SelectQuery) -> String {
+                format!("CREATE TABLE {} AS {}", name, query.build())
+            }
+
+            #shard_model_functions
+
+            #tenant_model_functions
+
+            #version_model_functions
+        }
+
+        impl ::prkorm::Model for #struct_name {
+            fn model_table() -> String {
+                Self::table_naming().resolve(#table)
+            }
+
+            fn model_columns() -> &'static [&'static str] {
+                &[#(#selectable_column_names),*]
+            }
+        }
+
+        #repository_trait
+
+    };
+    if module {
+        let module_ident = Ident::new(&format!("{}_query", to_snake_case(&struct_name.to_string())), struct_name.span());
+        return quote! {
+            #vis mod #module_ident {
+                use super::*;
+
+                #gen
+            }
+        }
+        .into();
+    }
+    gen.into()
+}
+
+/// Visibility that reaches from inside the `#[table(module)]` module as far as
+/// `vis` reaches from the struct's own module.
+fn nested_visibility(vis: &syn::Visibility) -> syn::Visibility {
+    let syn::Visibility::Restricted(restricted) = vis else {
+        return match vis {
+            syn::Visibility::Inherited => syn::parse_quote!(pub(super)),
+            _ => vis.clone(),
+        };
+    };
+    let path = &restricted.path;
+    match path.segments.first().map(|segment| segment.ident.to_string()).as_deref() {
+        Some("self") => {
+            let rest = path.segments.iter().skip(1);
+            syn::parse_quote!(pub(in super #(::#rest)*))
+        }
+        Some("super") => syn::parse_quote!(pub(in super::#path)),
+        _ => vis.clone(),
+    }
+}
+
+/// Trait bound for values of `field` in generated setters and conditions, and
+/// the function rendering such a value as a SQL literal.
+fn value_rendering(field: &syn::Field) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    value_trait(field).unwrap_or((quote!(ToString), quote!(::prkorm::literal)))
+}
+
+/// Value trait of fields that are not rendered through `ToString`. These
+/// traits are also implemented for `Option<T>`, rendering `None` as `NULL`.
+fn value_trait(field: &syn::Field) -> Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let type_name = field_type_name(&field.ty);
+    if is_uuid_binary(field) {
+        return Some((quote!(::prkorm::UuidValue), quote!(::prkorm::UuidValue::binary_literal)));
+    }
+    if is_bytes_type(&field.ty) {
+        return Some((quote!(::prkorm::BinaryValue), quote!(::prkorm::BinaryValue::sql_literal)));
+    }
+    if cfg!(feature = "uuid") && type_name.as_deref() == Some("Uuid") {
+        return Some((quote!(::prkorm::UuidValue), quote!(::prkorm::UuidValue::sql_literal)));
+    }
+    if cfg!(feature = "chrono")
+        && matches!(type_name.as_deref(), Some("NaiveDateTime" | "DateTime" | "NaiveDate" | "NaiveTime"))
+    {
+        return Some((quote!(::prkorm::DateTimeValue), quote!(::prkorm::DateTimeValue::sql_literal)));
+    }
+    None
+}
+
+/// Literal for the value `field` holds in `model`, as used by methods
+/// rendering a whole model. `None` in other `Option<T>` fields renders
+/// `NULL`; type errors point at the field.
+fn field_literal(field: &syn::Field, model: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let ident = field.ident.as_ref().unwrap();
+    if let Some((_, to_literal)) = value_trait(field) {
+        return quote!(#to_literal(&#model.#ident));
+    }
+    match option_inner(&field.ty) {
+        Some(inner) => {
+            let to_literal = quote_spanned!(inner.span()=> ::prkorm::literal::<#inner>);
+            quote! {
+                match &#model.#ident {
+                    Some(value) => #to_literal(value),
+                    None => String::from("NULL"),
+                }
+            }
+        }
+        None => {
+            let ty = &field.ty;
+            let to_literal = quote_spanned!(ty.span()=> ::prkorm::literal::<#ty>);
+            quote!(#to_literal(&#model.#ident))
+        }
+    }
+}
+
+/// Whether `field` is read but never written: a `#[generated]` column or a
+/// `#[select_only]` one maintained by the database, e.g. through triggers.
+fn is_read_only(field: &syn::Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("generated") || attr.path().is_ident("select_only"))
+}
+
+/// Whether `field` is left out of the default `select()` projection.
+fn is_never_selected(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("never_select"))
+}
+
+/// Whether `field` holds a date or time: a chrono type, or a field marked
+/// `#[datetime]`, e.g. a `String` read from a `DATETIME` column.
+fn is_datetime_field(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("datetime"))
+        || matches!(
+            field_type_name(&field.ty).as_deref(),
+            Some("NaiveDateTime" | "DateTime" | "NaiveDate" | "NaiveTime")
+        )
+}
+
+/// Whether `field` is stored as `BINARY(16)` through `UUID_TO_BIN()`.
+fn is_uuid_binary(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("uuid_binary"))
+}
+
+/// MySQL column type for `field` in `create_table_sql()`.
+fn sql_column_type(field: &syn::Field) -> String {
+    let mut ty = &field.ty;
+    if let Some(inner) = option_inner(ty) {
+        ty = inner;
+    }
+    if is_uuid_binary(field) {
+        return String::from("BINARY(16)");
+    }
+    if let syn::Type::Array(array) = ty {
+        let len = &array.len;
+        return format!("BINARY({})", quote!(#len));
+    }
+    if is_bytes_type(ty) {
+        return String::from("BLOB");
+    }
+    let sql_type = match field_type_name(ty).as_deref() {
+        Some("bool") => "TINYINT(1)",
+        Some("i8") => "TINYINT",
+        Some("u8") => "TINYINT UNSIGNED",
+        Some("i16") => "SMALLINT",
+        Some("u16") => "SMALLINT UNSIGNED",
+        Some("i32") => "INT",
+        Some("u32") => "INT UNSIGNED",
+        Some("i64" | "isize") => "BIGINT",
+        Some("u64" | "usize") => "BIGINT UNSIGNED",
+        Some("f32") => "FLOAT",
+        Some("f64") => "DOUBLE",
+        Some("String" | "str") => "VARCHAR(255)",
+        Some("NaiveDateTime" | "DateTime") if cfg!(feature = "chrono") => "DATETIME",
+        Some("NaiveDate") if cfg!(feature = "chrono") => "DATE",
+        Some("NaiveTime") if cfg!(feature = "chrono") => "TIME",
+        Some("Uuid") if cfg!(feature = "uuid") => "CHAR(36)",
+        _ => "TEXT",
+    };
+    String::from(sql_type)
+}
+
+/// `'value'` with single quotes doubled, for literals built at expansion time.
+fn sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    option_inner(ty).is_some()
+}
+
+/// `T` of an `Option<T>` type.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(syn::GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `ty` holds raw bytes: `Vec<u8>`, `[u8; N]` or `&[u8]`, optionally
+/// wrapped in `Option<...>`.
+fn is_bytes_type(ty: &syn::Type) -> bool {
+    let is_u8 = |ty: &syn::Type| matches!(ty, syn::Type::Path(path) if path.path.is_ident("u8"));
+    match ty {
+        syn::Type::Array(array) => is_u8(&array.elem),
+        syn::Type::Reference(reference) => matches!(&*reference.elem, syn::Type::Slice(slice) if is_u8(&slice.elem)),
+        syn::Type::Path(path) => {
+            let segment = match path.path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+            let inner = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                    Some(syn::GenericArgument::Type(inner)) => inner,
+                    _ => return false,
+                },
+                _ => return false,
+            };
+            (segment.ident == "Vec" && is_u8(inner)) || (segment.ident == "Option" && is_bytes_type(inner))
+        }
+        _ => false,
+    }
+}
+
+/// Column name as it appears in SQL: MySQL reserved words are backtick-quoted.
+fn sql_identifier(name: &str) -> String {
+    const RESERVED: &[&str] = &[
+        "add", "all", "alter", "and", "as", "asc", "before", "between", "both", "by", "call", "case",
+        "change", "check", "collate", "column", "condition", "constraint", "create", "cross",
+        "current_date", "current_time", "current_timestamp", "current_user", "database", "default",
+        "delete", "desc", "describe", "distinct", "div", "drop", "dual", "each", "else", "exists",
+        "explain", "false", "fetch", "for", "force", "foreign", "from", "fulltext", "function",
+        "generated", "grant", "group", "groups", "having", "if", "ignore", "in", "index", "inner",
+        "insert", "interval", "into", "is", "join", "key", "keys", "kill", "lateral", "leading",
+        "left", "like", "limit", "lines", "load", "lock", "match", "mod", "natural", "not", "null",
+        "of", "on", "option", "or", "order", "outer", "over", "partition", "precision", "primary",
+        "range", "rank", "read", "references", "regexp", "release", "rename", "repeat", "replace",
+        "require", "restrict", "return", "revoke", "right", "rlike", "row", "rows", "schema",
+        "select", "separator", "set", "show", "signal", "spatial", "sql", "table", "then", "to",
+        "trailing", "trigger", "true", "union", "unique", "unlock", "update", "usage", "use",
+        "using", "values", "when", "where", "while", "window", "with", "write", "xor",
+    ];
+    match RESERVED.contains(&name.to_lowercase().as_str()) {
+        true => format!("`{}`", name),
+        false => name.to_string(),
+    }
+}
+
+fn is_integer_type(ty: &syn::Type) -> bool {
+    matches!(
+        field_type_name(ty).as_deref(),
+        Some("i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize")
+    )
+}
+
+/// Last path segment of `ty`, looking through `Option<...>`.
+fn field_type_name(ty: &syn::Type) -> Option<String> {
+    if let Some(inner) = option_inner(ty) {
+        return field_type_name(inner);
+    }
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    Some(path.path.segments.last()?.ident.to_string())
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn to_plural(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| lower.ends_with(suffix)) {
+        return format!("{}es", name);
+    }
+    let mut chars = lower.chars().rev();
+    if let (Some('y'), Some(before)) = (chars.next(), chars.next()) {
+        if !"aeiou".contains(before) {
+            return format!("{}ies", &name[..name.len() - 1]);
+        }
+    }
+    format!("{}s", name)
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+struct Operations {
+    select: bool,
+    insert: bool,
+    update: bool,
+    delete: bool,
+}
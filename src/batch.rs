@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::transaction::Transaction;
+
+/// Script of several statements, each ending with `;`, for connections that
+/// allow multiple statements per query.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Batch {
+    statements: Vec<String>,
+}
+
+impl Batch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a statement, typically the output of a generated builder.
+    pub fn statement(mut self, sql: impl Into<String>) -> Self {
+        let sql = sql.into();
+        self.statements
+            .push(sql.trim_end().trim_end_matches(';').to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    /// Wraps the statements in `START TRANSACTION; ... COMMIT;`.
+    pub fn transaction(&self) -> Transaction {
+        self.statements
+            .iter()
+            .fold(Transaction::new(), |transaction, sql| {
+                transaction.statement(sql.as_str())
+            })
+    }
+
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Batch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, statement) in self.statements.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{};", statement)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statements_are_terminated_once() {
+        let batch = Batch::new().statement("SELECT 1;").statement("SELECT 2 \n");
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.build(), "SELECT 1;\nSELECT 2;");
+        assert_eq!(
+            batch.transaction().build(),
+            "START TRANSACTION;\nSELECT 1;\nSELECT 2;\nCOMMIT;"
+        );
+        assert!(Batch::new().build().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Opaque keyset-pagination cursor: the sort-key values of the last row of a
+/// page, encoded as URL-safe base64. Pass the token back to the select
+/// builder's `seek_after()` / `seek_before()` to fetch the next page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    values: Vec<String>,
+}
+
+/// Reason a cursor token could not be used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CursorError {
+    /// The token is not one produced by [`Cursor::encode`].
+    InvalidToken,
+    /// The token holds a different number of values than sort columns.
+    ColumnCountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CursorError::InvalidToken => f.write_str("invalid cursor token"),
+            CursorError::ColumnCountMismatch { expected, found } => write!(
+                f,
+                "cursor holds {} values but {} sort columns were given",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for CursorError {}
+
+impl Cursor {
+    pub fn new<T: ToString>(values: &[T]) -> Self {
+        Self {
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    pub fn encode(&self) -> String {
+        let mut payload = String::new();
+        for value in &self.values {
+            payload.push_str(&format!("{}:{}", value.len(), value));
+        }
+        encode_base64(payload.as_bytes())
+    }
+
+    pub fn decode(token: &str) -> Result<Self, CursorError> {
+        let payload = decode_base64(token).ok_or(CursorError::InvalidToken)?;
+        let mut rest = String::from_utf8(payload).map_err(|_| CursorError::InvalidToken)?;
+        let mut values = Vec::new();
+        while !rest.is_empty() {
+            let (length, tail) = rest.split_once(':').ok_or(CursorError::InvalidToken)?;
+            let length: usize = length.parse().map_err(|_| CursorError::InvalidToken)?;
+            if !tail.is_char_boundary(length.min(tail.len())) || tail.len() < length {
+                return Err(CursorError::InvalidToken);
+            }
+            values.push(tail[..length].to_string());
+            rest = tail[length..].to_string();
+        }
+        Ok(Self { values })
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let block = chunk.iter().enumerate().fold(0u32, |block, (i, byte)| {
+            block | u32::from(*byte) << (16 - 8 * i)
+        });
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(block >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn decode_base64(token: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(token.len() * 3 / 4);
+    for chunk in token.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut block = 0u32;
+        for (i, symbol) in chunk.iter().enumerate() {
+            let index = ALPHABET.iter().position(|c| c == symbol)?;
+            block |= (index as u32) << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            out.push((block >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let cursor = Cursor::new(&["2024-01-01 10:00:00", "", "café:3"]);
+        let token = cursor.encode();
+        assert!(token
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_'));
+        assert_eq!(Cursor::decode(&token), Ok(cursor));
+        assert_eq!(Cursor::decode(""), Ok(Cursor::new::<String>(&[])));
+    }
+
+    #[test]
+    fn decode_rejects_foreign_tokens() {
+        for token in ["not base64!", "A", "MTI", "NTph"] {
+            assert_eq!(Cursor::decode(token), Err(CursorError::InvalidToken));
+        }
+    }
+}
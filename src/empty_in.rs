@@ -0,0 +1,15 @@
+/// What a generated `where_*_in` or `having_*_in` method does when given an
+/// empty list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmptyIn {
+    /// Add `1 = 0`, so the query matches no rows.
+    #[default]
+    MatchNone,
+    /// Drop the condition, so the query matches every row. Opt in only
+    /// where an empty filter really means "no filter".
+    Skip,
+    /// Report `QueryBuildError::EmptyInList` from `try_build()`; `build()`
+    /// renders `1 = 0` like `MatchNone`.
+    Error,
+}
@@ -0,0 +1,70 @@
+use std::error::Error;
+use std::fmt;
+
+/// Problem found by a builder's `validate()` / `try_build()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QueryBuildError {
+    /// HAVING is used without GROUP BY and without any aggregate function.
+    HavingWithoutGroupBy,
+    /// The same WHERE condition was added more than once.
+    DuplicateCondition(String),
+    /// OFFSET is used without ORDER BY, so pages are not deterministic.
+    UnorderedPagination,
+    /// The query selects no columns.
+    EmptyProjection,
+    /// Two columns or two tables use the same alias.
+    AliasCollision(String),
+    /// OFFSET is used without LIMIT, which MySQL rejects.
+    OffsetWithoutLimit,
+    /// A GROUP BY expression is empty or calls an aggregate function.
+    InvalidGroupBy(String),
+    /// `group_by_alias` or `order_by_alias` names an alias the projection
+    /// does not define.
+    UnknownAlias(String),
+    /// A DISTINCT query is ordered by an expression it does not select.
+    OrderByNotSelected(String),
+    /// A `where_*_in` or `having_*_in` list on this column was empty under
+    /// `EmptyIn::Error`.
+    EmptyInList(String),
+    /// The built SQL failed to parse (only reported with the `validate` feature).
+    InvalidSql(String),
+    /// A `#[tenant_key]` model's query was built while no tenant was set.
+    NoTenant(String),
+}
+
+impl fmt::Display for QueryBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryBuildError::HavingWithoutGroupBy => {
+                f.write_str("HAVING is used without GROUP BY or an aggregate function")
+            }
+            QueryBuildError::DuplicateCondition(condition) => {
+                write!(f, "WHERE condition `{}` is repeated", condition)
+            }
+            QueryBuildError::UnorderedPagination => f.write_str("OFFSET is used without ORDER BY"),
+            QueryBuildError::EmptyProjection => f.write_str("the query selects no columns"),
+            QueryBuildError::AliasCollision(alias) => {
+                write!(f, "alias `{}` is used more than once", alias)
+            }
+            QueryBuildError::OffsetWithoutLimit => f.write_str("OFFSET is used without LIMIT"),
+            QueryBuildError::InvalidGroupBy(expression) => {
+                write!(f, "`{}` cannot be used in GROUP BY", expression)
+            }
+            QueryBuildError::UnknownAlias(alias) => {
+                write!(f, "alias `{}` is not selected", alias)
+            }
+            QueryBuildError::OrderByNotSelected(expression) => {
+                write!(f, "DISTINCT query orders by unselected `{}`", expression)
+            }
+            QueryBuildError::EmptyInList(column) => write!(f, "IN list for `{}` is empty", column),
+            QueryBuildError::InvalidSql(error) => write!(f, "generated SQL is invalid: {}", error),
+            QueryBuildError::NoTenant(table) => write!(
+                f,
+                "no tenant is set for `{}`; call prkorm::set_tenant() or prkorm::with_tenant() first",
+                table
+            ),
+        }
+    }
+}
+
+impl Error for QueryBuildError {}
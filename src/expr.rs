@@ -0,0 +1,149 @@
+use std::fmt;
+use std::ops;
+
+use crate::sql::quote_literal;
+
+/// Composable SQL expression for conditions built at runtime, e.g.
+/// `col("orders.status").eq("PENDING").and(col("total").gt(100))`.
+/// Strings convert into escaped literals; use [`col`] for column references.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Expr(String);
+
+/// Column (or any other trusted SQL) reference.
+pub fn col(name: &str) -> Expr {
+    Expr(name.to_string())
+}
+
+impl Expr {
+    /// Trusted SQL fragment, inserted as is.
+    pub fn raw(sql: &str) -> Self {
+        Self(sql.to_string())
+    }
+
+    pub fn eq(self, other: impl Into<Expr>) -> Self {
+        self.binary("=", other.into())
+    }
+
+    pub fn ne(self, other: impl Into<Expr>) -> Self {
+        self.binary("<>", other.into())
+    }
+
+    pub fn gt(self, other: impl Into<Expr>) -> Self {
+        self.binary(">", other.into())
+    }
+
+    pub fn ge(self, other: impl Into<Expr>) -> Self {
+        self.binary(">=", other.into())
+    }
+
+    pub fn lt(self, other: impl Into<Expr>) -> Self {
+        self.binary("<", other.into())
+    }
+
+    pub fn le(self, other: impl Into<Expr>) -> Self {
+        self.binary("<=", other.into())
+    }
+
+    pub fn like(self, pattern: impl Into<Expr>) -> Self {
+        self.binary("LIKE", pattern.into())
+    }
+
+    pub fn is_null(self) -> Self {
+        Self(format!("{} IS NULL", self.0))
+    }
+
+    pub fn is_not_null(self) -> Self {
+        Self(format!("{} IS NOT NULL", self.0))
+    }
+
+    pub fn between(self, low: impl Into<Expr>, high: impl Into<Expr>) -> Self {
+        Self(format!(
+            "{} BETWEEN {} AND {}",
+            self.0,
+            low.into().0,
+            high.into().0
+        ))
+    }
+
+    pub fn in_list<T: Into<Expr>>(self, values: impl IntoIterator<Item = T>) -> Self {
+        let values: Vec<String> = values.into_iter().map(|value| value.into().0).collect();
+        Self(format!("{} IN ({})", self.0, values.join(", ")))
+    }
+
+    pub fn and(self, other: Expr) -> Self {
+        self.binary("AND", other)
+    }
+
+    /// Parenthesized, so the result can be combined with `and` safely.
+    pub fn or(self, other: Expr) -> Self {
+        Self(format!("({} OR {})", self.0, other.0))
+    }
+
+    fn binary(self, operator: &str, other: Expr) -> Self {
+        Self(format!("{} {} {}", self.0, operator, other.0))
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// `!expr` renders `NOT (expr)`.
+impl ops::Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        Expr(format!("NOT ({})", self.0))
+    }
+}
+
+impl From<&str> for Expr {
+    fn from(value: &str) -> Self {
+        Self(quote_literal(value))
+    }
+}
+
+impl From<String> for Expr {
+    fn from(value: String) -> Self {
+        Self(quote_literal(&value))
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Self {
+        Self(String::from(if value { "TRUE" } else { "FALSE" }))
+    }
+}
+
+macro_rules! number_expr {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Expr {
+                fn from(value: $ty) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+number_expr!(i8, i16, i32, i64, u8, u16, u32, u64, usize, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_literals_and_columns_are_not() {
+        let expr = col("orders.status")
+            .eq("it's")
+            .and(col("total").gt(100).or(!col("paid").eq(true)));
+        assert_eq!(
+            expr.to_string(),
+            "orders.status = 'it''s' AND (total > 100 OR NOT (paid = TRUE))"
+        );
+        assert_eq!(col("id").in_list([1, 2]).to_string(), "id IN (1, 2)");
+    }
+}
@@ -0,0 +1,288 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::order::Order;
+use crate::sql::quote_literal;
+
+/// Comparison a [`Filter`] applies to one column. Values are rendered as
+/// escaped string literals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilterOp {
+    Eq(String),
+    Ne(String),
+    Gt(String),
+    Gte(String),
+    Lt(String),
+    Lte(String),
+    Like(String),
+    In(Vec<String>),
+    IsNull,
+    IsNotNull,
+}
+
+impl FilterOp {
+    /// Parses an operator name (`eq`, `ne`, `gt`, `gte`, `lt`, `lte`, `like`
+    /// or `in`) with its value; `in` takes a comma-separated list.
+    pub fn parse(operator: &str, value: &str) -> Result<Self, FilterError> {
+        let value = value.to_string();
+        match operator {
+            "eq" => Ok(FilterOp::Eq(value)),
+            "ne" => Ok(FilterOp::Ne(value)),
+            "gt" => Ok(FilterOp::Gt(value)),
+            "gte" => Ok(FilterOp::Gte(value)),
+            "lt" => Ok(FilterOp::Lt(value)),
+            "lte" => Ok(FilterOp::Lte(value)),
+            "like" => Ok(FilterOp::Like(value)),
+            "in" => Ok(FilterOp::In(
+                value
+                    .split(',')
+                    .map(|item| item.trim().to_string())
+                    .filter(|item| !item.is_empty())
+                    .collect(),
+            )),
+            _ => Err(FilterError::UnknownOperator(operator.to_string())),
+        }
+    }
+
+    /// Renders the condition on `column`.
+    pub fn render(&self, column: &str) -> String {
+        match self {
+            FilterOp::Eq(value) => format!("{} = {}", column, quote_literal(value)),
+            FilterOp::Ne(value) => format!("{} != {}", column, quote_literal(value)),
+            FilterOp::Gt(value) => format!("{} > {}", column, quote_literal(value)),
+            FilterOp::Gte(value) => format!("{} >= {}", column, quote_literal(value)),
+            FilterOp::Lt(value) => format!("{} < {}", column, quote_literal(value)),
+            FilterOp::Lte(value) => format!("{} <= {}", column, quote_literal(value)),
+            FilterOp::Like(value) => format!("{} LIKE {}", column, quote_literal(value)),
+            FilterOp::In(values) => {
+                let values: Vec<String> = values.iter().map(|value| quote_literal(value)).collect();
+                format!("{} IN ({})", column, values.join(", "))
+            }
+            FilterOp::IsNull => format!("{} IS NULL", column),
+            FilterOp::IsNotNull => format!("{} IS NOT NULL", column),
+        }
+    }
+}
+
+/// Declarative filter over a model's columns, applied with the select
+/// builder's `apply_filter()`. Column names are checked against the model
+/// when the filter is applied; `#[never_select]` columns are rejected.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Filter {
+    pub conditions: Vec<(String, FilterOp)>,
+    pub order_by: Vec<(String, Order)>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Reason a filter could not be parsed or applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FilterError {
+    /// The column is not a field of the model.
+    UnknownColumn(String),
+    /// The operator name is not one [`FilterOp::parse`] accepts.
+    UnknownOperator(String),
+    /// A sort direction other than `asc` or `desc`.
+    InvalidDirection(String),
+    /// The document does not have the expected shape.
+    InvalidSpec(String),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::UnknownColumn(column) => write!(f, "unknown column `{}`", column),
+            FilterError::UnknownOperator(operator) => {
+                write!(f, "unknown filter operator `{}`", operator)
+            }
+            FilterError::InvalidDirection(direction) => {
+                write!(f, "invalid sort direction `{}`", direction)
+            }
+            FilterError::InvalidSpec(reason) => write!(f, "invalid filter: {}", reason),
+        }
+    }
+}
+
+impl Error for FilterError {}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a document such as
+    /// `{"where": {"order_status": {"eq": "PENDING"}}, "order_by": [{"created_at": "desc"}], "limit": 20}`.
+    /// A bare value in `where` means `eq`, `null` means `IS NULL`, and
+    /// `{"is_null": false}` means `IS NOT NULL`.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, FilterError> {
+        use serde_json::Value;
+
+        let document: Value =
+            serde_json::from_str(json).map_err(|e| FilterError::InvalidSpec(e.to_string()))?;
+        let Value::Object(document) = document else {
+            return Err(FilterError::InvalidSpec(String::from("expected an object")));
+        };
+        let mut filter = Filter::new();
+        for (key, value) in document {
+            match (key.as_str(), value) {
+                ("where", Value::Object(columns)) => {
+                    for (column, condition) in columns {
+                        match condition {
+                            Value::Object(operators) => {
+                                for (operator, value) in operators {
+                                    let op = json_op(&operator, value)?;
+                                    filter.conditions.push((column.clone(), op));
+                                }
+                            }
+                            Value::Null => filter.conditions.push((column, FilterOp::IsNull)),
+                            value => filter
+                                .conditions
+                                .push((column, FilterOp::Eq(json_scalar(value)?))),
+                        }
+                    }
+                }
+                ("order_by", Value::Array(orders)) => {
+                    for order in orders {
+                        match order {
+                            Value::String(column) => filter.order_by.push((column, Order::Asc)),
+                            Value::Object(columns) => {
+                                for (column, direction) in columns {
+                                    let direction = direction.as_str().unwrap_or_default();
+                                    let direction = Order::parse(direction).ok_or_else(|| {
+                                        FilterError::InvalidDirection(direction.to_string())
+                                    })?;
+                                    filter.order_by.push((column, direction));
+                                }
+                            }
+                            _ => {
+                                return Err(FilterError::InvalidSpec(String::from(
+                                    "order_by entries must be strings or objects",
+                                )))
+                            }
+                        }
+                    }
+                }
+                ("limit", value) => filter.limit = Some(json_u32("limit", &value)?),
+                ("offset", value) => filter.offset = Some(json_u32("offset", &value)?),
+                (key, _) => {
+                    return Err(FilterError::InvalidSpec(format!(
+                        "unexpected or malformed `{}`",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(filter)
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_op(operator: &str, value: serde_json::Value) -> Result<FilterOp, FilterError> {
+    use serde_json::Value;
+
+    match (operator, value) {
+        ("in", Value::Array(values)) => Ok(FilterOp::In(
+            values
+                .into_iter()
+                .map(json_scalar)
+                .collect::<Result<_, _>>()?,
+        )),
+        ("is_null", Value::Bool(true)) => Ok(FilterOp::IsNull),
+        ("is_null", Value::Bool(false)) => Ok(FilterOp::IsNotNull),
+        ("in" | "is_null", _) => Err(FilterError::InvalidSpec(format!(
+            "unexpected value for `{}`",
+            operator
+        ))),
+        (operator, value) => FilterOp::parse(operator, &json_scalar(value)?),
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_scalar(value: serde_json::Value) -> Result<String, FilterError> {
+    use serde_json::Value;
+
+    match value {
+        Value::String(value) => Ok(value),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Bool(value) => Ok(String::from(if value { "1" } else { "0" })),
+        _ => Err(FilterError::InvalidSpec(String::from(
+            "filter values must be strings, numbers or booleans",
+        ))),
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_u32(key: &str, value: &serde_json::Value) -> Result<u32, FilterError> {
+    value
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| FilterError::InvalidSpec(format!("`{}` must be a number", key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_known_operators_only() {
+        assert_eq!(
+            FilterOp::parse("in", "1, 2,,3"),
+            Ok(FilterOp::In(vec![
+                String::from("1"),
+                String::from("2"),
+                String::from("3")
+            ]))
+        );
+        assert_eq!(
+            FilterOp::parse("between", "1"),
+            Err(FilterError::UnknownOperator(String::from("between")))
+        );
+    }
+
+    #[test]
+    fn render_quotes_values() {
+        assert_eq!(
+            FilterOp::Like(String::from("o'%")).render("name"),
+            "name LIKE 'o''%'"
+        );
+        assert_eq!(FilterOp::IsNotNull.render("name"), "name IS NOT NULL");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json_reads_conditions_order_and_limit() {
+        let filter = Filter::from_json(
+            r#"{"where": {"status": "new", "deleted_at": null, "id": {"in": [1, 2]}},
+                "order_by": [{"id": "desc"}], "limit": 20}"#,
+        )
+        .unwrap();
+        assert_eq!(filter.conditions.len(), 3);
+        assert!(filter
+            .conditions
+            .contains(&(String::from("deleted_at"), FilterOp::IsNull)));
+        assert_eq!(filter.order_by, vec![(String::from("id"), Order::Desc)]);
+        assert_eq!(filter.limit, Some(20));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json_reports_malformed_documents() {
+        assert!(matches!(
+            Filter::from_json("[]"),
+            Err(FilterError::InvalidSpec(_))
+        ));
+        assert_eq!(
+            Filter::from_json(r#"{"where": {"id": {"near": 1}}}"#),
+            Err(FilterError::UnknownOperator(String::from("near")))
+        );
+        assert_eq!(
+            Filter::from_json(r#"{"order_by": [{"id": "up"}]}"#),
+            Err(FilterError::InvalidDirection(String::from("up")))
+        );
+        assert!(matches!(
+            Filter::from_json(r#"{"limit": -1}"#),
+            Err(FilterError::InvalidSpec(_))
+        ));
+    }
+}
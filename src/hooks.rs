@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+
+use crate::naming::TableNaming;
+
+/// Callback receiving the SQL rendered by a generated builder.
+pub type BuildObserver = fn(&str);
+
+static OBSERVERS: RwLock<Vec<BuildObserver>> = RwLock::new(Vec::new());
+
+/// Registers a callback invoked with the final SQL every time a generated
+/// builder is built, e.g. to log, measure or audit queries in one place.
+pub fn add_build_observer(observer: BuildObserver) {
+    OBSERVERS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(observer);
+}
+
+/// Removes every callback registered with [`add_build_observer`].
+pub fn clear_build_observers() {
+    OBSERVERS.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Kind of statement a generated builder renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Parts of a query handed to query middleware before it is rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryParts {
+    pub kind: QueryKind,
+    /// Table the builder targets, before `naming` is applied.
+    pub table: String,
+    /// Prefix, schema and suffix the table name is resolved with.
+    pub naming: TableNaming,
+    /// Extra predicates ANDed to the WHERE clause. Ignored for inserts.
+    pub conditions: Vec<String>,
+    /// Tags appended to the SQL as a `/*key='value'*/` comment.
+    pub comments: BTreeMap<String, String>,
+}
+
+/// Function rewriting the parts of every query built by a generated builder.
+pub type QueryMiddleware = fn(&mut QueryParts);
+
+static MIDDLEWARE: RwLock<Vec<QueryMiddleware>> = RwLock::new(Vec::new());
+
+/// Registers a middleware run, in registration order, each time a generated
+/// builder is built, e.g. to add audit predicates, rename tables or tag
+/// queries without touching call sites.
+pub fn add_query_middleware(middleware: QueryMiddleware) {
+    MIDDLEWARE
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(middleware);
+}
+
+/// Removes every middleware registered with [`add_query_middleware`].
+pub fn clear_query_middleware() {
+    MIDDLEWARE
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+#[doc(hidden)]
+pub fn run_query_middleware(parts: &mut QueryParts) {
+    let middleware = MIDDLEWARE.read().unwrap_or_else(|e| e.into_inner());
+    for middleware in middleware.iter() {
+        middleware(parts);
+    }
+}
+
+/// Per-builder observer set with `on_build()`. It is not part of the query,
+/// so it is ignored when builders are compared or hashed.
+#[doc(hidden)]
+#[derive(Clone, Copy, Default)]
+pub struct ObserverSlot(Option<BuildObserver>);
+
+impl ObserverSlot {
+    pub fn new(observer: BuildObserver) -> Self {
+        Self(Some(observer))
+    }
+
+    /// Runs the builder's observer, then the global ones.
+    pub fn notify(&self, sql: &str) {
+        if let Some(observer) = self.0 {
+            observer(sql);
+        }
+        let observers = OBSERVERS.read().unwrap_or_else(|e| e.into_inner());
+        for observer in observers.iter() {
+            observer(sql);
+        }
+    }
+}
+
+impl fmt::Debug for ObserverSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_some() { "Some(..)" } else { "None" })
+    }
+}
+
+impl PartialEq for ObserverSlot {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ObserverSlot {}
+
+impl Hash for ObserverSlot {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Moves each child into the `Vec` of the parent with the same key, keeping
+/// the children's order, and returns the children no parent claimed.
+///
+/// ```rust
+/// # struct Customer { id: u32, orders: Vec<Order> }
+/// # struct Order { customer_id: u32 }
+/// let mut customers = vec![Customer { id: 1, orders: Vec::new() }];
+/// let orders = vec![Order { customer_id: 1 }, Order { customer_id: 2 }];
+/// let orphans = prkorm::attach_many(
+///     &mut customers,
+///     orders,
+///     |customer| customer.id,
+///     |order| order.customer_id,
+///     |customer| &mut customer.orders,
+/// );
+/// assert_eq!(customers[0].orders.len(), 1);
+/// assert_eq!(orphans.len(), 1);
+/// ```
+pub fn attach_many<P, C, K: Eq + Hash>(
+    parents: &mut [P],
+    children: Vec<C>,
+    parent_key: impl Fn(&P) -> K,
+    child_key: impl Fn(&C) -> K,
+    mut children_of: impl FnMut(&mut P) -> &mut Vec<C>,
+) -> Vec<C> {
+    let index: HashMap<K, usize> = parents
+        .iter()
+        .enumerate()
+        .map(|(i, parent)| (parent_key(parent), i))
+        .collect();
+    let mut orphans = Vec::new();
+    for child in children {
+        match index.get(&child_key(&child)) {
+            Some(&i) => children_of(&mut parents[i]).push(child),
+            None => orphans.push(child),
+        }
+    }
+    orphans
+}
+
+/// Folds the rows of a parent/child join into one parent per key, in order
+/// of first appearance. `None` children (from a LEFT JOIN without a match)
+/// leave the parent's `Vec` empty.
+pub fn hydrate_joined<P, C, K: Eq + Hash>(
+    rows: impl IntoIterator<Item = (P, Option<C>)>,
+    parent_key: impl Fn(&P) -> K,
+    mut children_of: impl FnMut(&mut P) -> &mut Vec<C>,
+) -> Vec<P> {
+    let mut parents: Vec<P> = Vec::new();
+    let mut index: HashMap<K, usize> = HashMap::new();
+    for (parent, child) in rows {
+        let i = *index.entry(parent_key(&parent)).or_insert_with(|| {
+            parents.push(parent);
+            parents.len() - 1
+        });
+        if let Some(child) = child {
+            children_of(&mut parents[i]).push(child);
+        }
+    }
+    parents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hydrate_joined_groups_rows_by_parent() {
+        let rows = vec![
+            ((1, Vec::new()), Some("a")),
+            ((2, Vec::new()), None),
+            ((1, Vec::new()), Some("b")),
+        ];
+        let parents = hydrate_joined(rows, |parent| parent.0, |parent| &mut parent.1);
+        assert_eq!(parents, vec![(1, vec!["a", "b"]), (2, Vec::new())]);
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// Unit of a MySQL `INTERVAL` expression, used by the generated
+/// `*_interval*` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Microsecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+    /// SQL keyword for the unit.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Microsecond => "MICROSECOND",
+            Unit::Second => "SECOND",
+            Unit::Minute => "MINUTE",
+            Unit::Hour => "HOUR",
+            Unit::Day => "DAY",
+            Unit::Week => "WEEK",
+            Unit::Month => "MONTH",
+            Unit::Quarter => "QUARTER",
+            Unit::Year => "YEAR",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
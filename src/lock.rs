@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::model::Model;
+use crate::naming::TableNaming;
+
+/// `LOCK TABLES` statement for maintenance scripts, e.g.
+/// `OrderModel::lock_write().read_model::<Customer>()` renders
+/// `LOCK TABLES orders WRITE, customers READ`. Tables named by string get
+/// the process-wide prefix and schema; models use their own naming.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LockTables {
+    locks: Vec<(String, &'static str)>,
+}
+
+impl LockTables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn read(self, table: &str) -> Self {
+        self.lock(TableNaming::default().resolve(table), "READ")
+    }
+
+    pub fn write(self, table: &str) -> Self {
+        self.lock(TableNaming::default().resolve(table), "WRITE")
+    }
+
+    /// Locks the table of `M` for reading, honouring `#[table_schema]`.
+    pub fn read_model<M: Model>(self) -> Self {
+        self.lock(M::model_table(), "READ")
+    }
+
+    /// Locks the table of `M` for writing, honouring `#[table_schema]`.
+    pub fn write_model<M: Model>(self) -> Self {
+        self.lock(M::model_table(), "WRITE")
+    }
+
+    fn lock(mut self, table: String, lock: &'static str) -> Self {
+        self.locks.push((table, lock));
+        self
+    }
+
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+
+    /// Statement releasing every lock held by the session.
+    pub fn unlock() -> String {
+        String::from("UNLOCK TABLES")
+    }
+}
+
+impl fmt::Display for LockTables {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locks: Vec<String> = self
+            .locks
+            .iter()
+            .map(|(table, lock)| format!("{} {}", table, lock))
+            .collect();
+        write!(f, "LOCK TABLES {}", locks.join(", "))
+    }
+}
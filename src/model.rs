@@ -0,0 +1,10 @@
+/// Implemented for every struct deriving `Table`, so builders can join
+/// another model given only its type, e.g. `left_join_model_as::<Customer>`.
+pub trait Model {
+    /// Table name as the model's own queries render it, with schema and
+    /// prefix applied.
+    fn model_table() -> String;
+
+    /// Column names in declaration order, without `#[never_select]` fields.
+    fn model_columns() -> &'static [&'static str];
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+/// MySQL modifier placed right after the `SELECT` keyword. Modifiers are
+/// rendered in the order MySQL's grammar expects, whatever order they were
+/// added in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectModifier {
+    HighPriority,
+    StraightJoin,
+    SqlSmallResult,
+    SqlBigResult,
+    SqlBufferResult,
+    SqlNoCache,
+    SqlCalcFoundRows,
+}
+
+impl SelectModifier {
+    /// SQL keyword for the modifier.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SelectModifier::HighPriority => "HIGH_PRIORITY",
+            SelectModifier::StraightJoin => "STRAIGHT_JOIN",
+            SelectModifier::SqlSmallResult => "SQL_SMALL_RESULT",
+            SelectModifier::SqlBigResult => "SQL_BIG_RESULT",
+            SelectModifier::SqlBufferResult => "SQL_BUFFER_RESULT",
+            SelectModifier::SqlNoCache => "SQL_NO_CACHE",
+            SelectModifier::SqlCalcFoundRows => "SQL_CALC_FOUND_ROWS",
+        }
+    }
+}
+
+impl fmt::Display for SelectModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// MySQL modifier placed right after the `INSERT` keyword. `LowPriority`,
+/// `Delayed` and `HighPriority` exclude each other; adding one replaces the
+/// others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InsertModifier {
+    LowPriority,
+    Delayed,
+    HighPriority,
+    Ignore,
+}
+
+impl InsertModifier {
+    /// SQL keyword for the modifier.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InsertModifier::LowPriority => "LOW_PRIORITY",
+            InsertModifier::Delayed => "DELAYED",
+            InsertModifier::HighPriority => "HIGH_PRIORITY",
+            InsertModifier::Ignore => "IGNORE",
+        }
+    }
+
+    /// Whether the modifier sets the statement's priority.
+    pub fn is_priority(&self) -> bool {
+        !matches!(self, InsertModifier::Ignore)
+    }
+}
+
+impl fmt::Display for InsertModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::RwLock;
+
+use crate::sql::quote_literal;
+
+static TABLE_PREFIX: RwLock<String> = RwLock::new(String::new());
+static DEFAULT_SCHEMA: RwLock<String> = RwLock::new(String::new());
+
+/// Sets a prefix added to every table a generated builder renders, e.g.
+/// `set_table_prefix("tenant_42_")` turns `orders` into `tenant_42_orders`.
+/// Builders can override it with `table_prefix()`; pass `""` to clear it.
+///
+/// Tables joined as `JOIN name ON ...` are renamed too, whether or not they
+/// belong to a derived model. Join a table that keeps its name under an
+/// alias (`JOIN countries c ON ...`) or qualified with its schema
+/// (`JOIN shared.countries ON ...`).
+pub fn set_table_prefix(prefix: &str) {
+    *TABLE_PREFIX.write().unwrap_or_else(|e| e.into_inner()) = prefix.to_string();
+}
+
+/// Sets the schema (database) every generated builder qualifies its tables
+/// with, e.g. `analytics.orders`. Builders can override it with
+/// `in_schema()`; pass `""` to go back to the connection's default database.
+pub fn set_default_schema(schema: &str) {
+    *DEFAULT_SCHEMA.write().unwrap_or_else(|e| e.into_inner()) = schema.to_string();
+}
+
+/// Per-builder overrides of the process-wide table naming, handed to query
+/// middleware as [`QueryParts::naming`](crate::QueryParts::naming).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableNaming {
+    prefix: Option<String>,
+    schema: Option<String>,
+    suffix: String,
+}
+
+impl TableNaming {
+    /// Overrides the prefix set with [`set_table_prefix`].
+    pub fn with_prefix(self, prefix: &str) -> Self {
+        Self {
+            prefix: Some(prefix.to_string()),
+            ..self
+        }
+    }
+
+    /// Overrides the schema set with [`set_default_schema`].
+    pub fn with_schema(self, schema: &str) -> Self {
+        Self {
+            schema: Some(schema.to_string()),
+            ..self
+        }
+    }
+
+    /// Sets the suffix appended to the builder's own table, e.g. a
+    /// [`shard_suffix`].
+    pub fn with_suffix(self, suffix: &str) -> Self {
+        Self {
+            suffix: suffix.to_string(),
+            ..self
+        }
+    }
+
+    /// Whether tables keep their own names: no prefix, schema or suffix applies.
+    #[doc(hidden)]
+    pub fn is_identity(&self) -> bool {
+        let no_prefix = match &self.prefix {
+            Some(prefix) => prefix.is_empty(),
+            None => TABLE_PREFIX
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .is_empty(),
+        };
+        no_prefix && self.suffix.is_empty() && self.schema().is_none()
+    }
+
+    /// Returns the name the builder's own `table` is rendered with.
+    pub fn resolve(&self, table: &str) -> String {
+        self.qualify(&format!("{}{}", table, self.suffix))
+    }
+
+    /// Returns another table of the query as `name alias`, keeping the
+    /// original name as alias when the prefix or schema renames it.
+    pub fn resolve_as(&self, table: &str, alias: &str) -> String {
+        let renamed = self.qualify(table);
+        match renamed == alias {
+            true => renamed,
+            false => format!("{} {}", renamed, alias),
+        }
+    }
+
+    fn qualify(&self, table: &str) -> String {
+        let table = self.prefixed(table);
+        match self.schema() {
+            Some(schema) => format!("{}.{}", schema, table),
+            None => table,
+        }
+    }
+
+    /// `information_schema` condition matching the builder's own `table`,
+    /// in the current database unless a schema is set.
+    pub fn schema_filter(&self, table: &str) -> String {
+        let schema = match self.schema() {
+            Some(schema) => quote_literal(&schema),
+            None => String::from("DATABASE()"),
+        };
+        format!(
+            "TABLE_SCHEMA = {} AND TABLE_NAME = {}",
+            schema,
+            quote_literal(&self.prefixed(&format!("{}{}", table, self.suffix)))
+        )
+    }
+
+    fn prefixed(&self, table: &str) -> String {
+        match &self.prefix {
+            Some(prefix) => format!("{}{}", prefix, table),
+            None => format!(
+                "{}{}",
+                TABLE_PREFIX.read().unwrap_or_else(|e| e.into_inner()),
+                table
+            ),
+        }
+    }
+
+    fn schema(&self) -> Option<String> {
+        let schema = match &self.schema {
+            Some(schema) => schema.clone(),
+            None => DEFAULT_SCHEMA
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone(),
+        };
+        match schema.is_empty() {
+            true => None,
+            false => Some(schema),
+        }
+    }
+
+    /// Renames the table of a `JOIN table ON ...` clause, keeping the
+    /// original name as alias so the ON condition still resolves. Joins on
+    /// subqueries, aliased or qualified tables are left untouched.
+    pub fn resolve_join(&self, join: &str) -> String {
+        let Some(start) = join.find("JOIN ").map(|index| index + "JOIN ".len()) else {
+            return join.to_string();
+        };
+        let rest = &join[start..];
+        let Some((table, tail)) = rest.split_once(' ') else {
+            return join.to_string();
+        };
+        let is_identifier =
+            !table.is_empty() && table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier || !tail.starts_with("ON ") {
+            return join.to_string();
+        }
+        format!(
+            "{}{} {}",
+            &join[..start],
+            self.resolve_as(table, table),
+            tail
+        )
+    }
+}
+
+/// Returns the table suffix of the shard `value` belongs to, e.g. `_07` for
+/// 16 shards. Integer values are routed by `value % shards`, anything else by
+/// a stable FNV-1a hash of its bytes.
+pub fn shard_suffix(value: &str, shards: u32) -> String {
+    let shards = u64::from(shards.max(1));
+    let index = match value.parse::<u64>() {
+        Ok(number) => number % shards,
+        Err(_) => {
+            value.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            }) % shards
+        }
+    };
+    let width = (shards - 1).to_string().len().max(2);
+    format!("_{:0width$}", index, width = width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_filter_matches_the_resolved_table() {
+        let naming = TableNaming::default()
+            .with_prefix("app_")
+            .with_schema("it's")
+            .with_suffix("_01");
+        assert_eq!(
+            naming.schema_filter("orders"),
+            "TABLE_SCHEMA = 'it''s' AND TABLE_NAME = 'app_orders_01'"
+        );
+    }
+
+    #[test]
+    fn shard_suffix_routes_integers_by_modulo() {
+        assert_eq!(shard_suffix("23", 16), "_07");
+        assert_eq!(shard_suffix("5", 1000), "_005");
+        assert_eq!(shard_suffix("5", 0), "_00");
+    }
+
+    #[test]
+    fn shard_suffix_hashes_other_values_stably() {
+        let suffix = shard_suffix("acme", 16);
+        assert_eq!(suffix, shard_suffix("acme", 16));
+        let index: u32 = suffix[1..].parse().unwrap();
+        assert!(index < 16);
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Sort direction used by the generated `order_by_*` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl Order {
+    /// SQL keyword for the direction.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Order::Asc => "ASC",
+            Order::Desc => "DESC",
+        }
+    }
+
+    /// Parses a user-supplied direction, `asc` or `desc` in any case.
+    pub fn parse(direction: &str) -> Option<Self> {
+        match direction.trim() {
+            d if d.eq_ignore_ascii_case("asc") => Some(Order::Asc),
+            d if d.eq_ignore_ascii_case("desc") => Some(Order::Desc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_asc_and_desc_in_any_case() {
+        assert_eq!(Order::parse(" DESC "), Some(Order::Desc));
+        assert_eq!(Order::parse("asc"), Some(Order::Asc));
+        assert_eq!(Order::parse("ascending"), None);
+    }
+}
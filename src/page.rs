@@ -0,0 +1,89 @@
+/// Rows and count statements for one page of a select builder, produced by
+/// its `build_paged()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PagedQuery {
+    /// The query with `LIMIT` and `OFFSET` for the requested page.
+    pub rows: String,
+    /// `SELECT COUNT(*)` over the same filters, without ordering or limits.
+    pub count: String,
+    /// Requested page, starting at 1.
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl PagedQuery {
+    /// Wraps the rows fetched with `rows` and the total read with `count`.
+    pub fn into_page<T>(&self, items: Vec<T>, total: u64) -> Page<T> {
+        Page {
+            items,
+            page: self.page,
+            per_page: self.per_page,
+            total,
+        }
+    }
+}
+
+/// One page of results with the information needed to render pagination.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Page number, starting at 1.
+    pub page: u32,
+    pub per_page: u32,
+    /// Number of rows matching the query across all pages.
+    pub total: u64,
+}
+
+impl<T> Page<T> {
+    pub fn total_pages(&self) -> u64 {
+        match self.per_page {
+            0 => 0,
+            per_page => self.total.div_ceil(u64::from(per_page)),
+        }
+    }
+
+    pub fn has_next(&self) -> bool {
+        u64::from(self.page) < self.total_pages()
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page > 1
+    }
+
+    /// Converts the items, keeping the pagination information.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            page: self.page,
+            per_page: self.per_page,
+            total: self.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_navigation() {
+        let query = PagedQuery {
+            rows: String::new(),
+            count: String::new(),
+            page: 2,
+            per_page: 10,
+        };
+        let page = query.into_page(vec![1, 2], 21).map(|item| item * 10);
+        assert_eq!(page.items, vec![10, 20]);
+        assert_eq!(page.total_pages(), 3);
+        assert!(page.has_next() && page.has_previous());
+        assert_eq!(
+            Page::<u8> {
+                per_page: 0,
+                ..query.into_page(Vec::new(), 5)
+            }
+            .total_pages(),
+            0
+        );
+    }
+}
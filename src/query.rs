@@ -0,0 +1,36 @@
+use std::fmt;
+
+/// Implemented by the generated select and insert builders, so helpers such
+/// as loggers or execution wrappers can be written once for all models.
+pub trait Query: fmt::Display {
+    /// Name of the table the statement targets.
+    fn table_name(&self) -> &str;
+
+    /// Renders the SQL statement, as the builder's own `build()` does.
+    fn build(&self) -> String;
+}
+
+/// Implemented by the generated select builders.
+pub trait SelectQuery: Query {
+    /// Alias the table is referenced by in the rendered query.
+    fn table_alias(&self) -> &str;
+
+    /// Primary key declared with `#[primary_key]`, empty when not set.
+    fn primary_key(&self) -> &str;
+}
+
+/// Implemented by the generated insert builders. Update and delete builders
+/// only render SQL through their `*_where_*` methods, so an unfinished one
+/// can never be passed around as a statement without a WHERE clause.
+///
+/// ```compile_fail
+/// # use prkorm::Table;
+/// #[derive(Table)]
+/// #[table_name("customers")]
+/// struct Customer {
+///     id: u32,
+/// }
+///
+/// let everyone = Customer::delete().to_string();
+/// ```
+pub trait WriteQuery: Query {}
@@ -0,0 +1,62 @@
+use crate::expr::Expr;
+use crate::sql::quote_literal;
+
+/// Reusable bundle of joins, WHERE conditions and ORDER BY terms, e.g. "not
+/// soft-deleted" or "belongs to the current tenant", applied with `.scope()`.
+///
+/// Each condition is parenthesized before it is ANDed to a statement's WHERE
+/// clause. Select builders take all three parts; update and delete builders
+/// panic when given a scope with joins or ORDER BY terms.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Scope {
+    joins: Vec<String>,
+    conditions: Vec<String>,
+    order_by: Vec<String>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a raw join such as `JOIN tenants ON tenants.id = orders.tenant_id`.
+    pub fn join_str(mut self, join: &str) -> Self {
+        self.joins.push(join.to_string());
+        self
+    }
+
+    pub fn where_str(mut self, condition: &str) -> Self {
+        self.conditions.push(condition.to_string());
+        self
+    }
+
+    pub fn where_expr(self, condition: Expr) -> Self {
+        self.where_str(&condition.to_string())
+    }
+
+    /// Adds `column = 'value'`, escaping the value.
+    pub fn where_eq(self, column: &str, value: impl ToString) -> Self {
+        self.where_str(&format!(
+            "{} = {}",
+            column,
+            quote_literal(&value.to_string())
+        ))
+    }
+
+    pub fn order_by_str(mut self, order_by: &str) -> Self {
+        self.order_by.push(order_by.to_string());
+        self
+    }
+
+    pub fn joins(&self) -> &[String] {
+        &self.joins
+    }
+
+    pub fn conditions(&self) -> &[String] {
+        &self.conditions
+    }
+
+    pub fn order_by(&self) -> &[String] {
+        &self.order_by
+    }
+}
@@ -0,0 +1,418 @@
+use std::collections::BTreeMap;
+
+/// Replaces references qualified with `from.` by `to.` in a rendered
+/// fragment, leaving longer identifiers that merely end with `from` and
+/// quoted string literals alone.
+#[doc(hidden)]
+pub fn requalify(fragment: &str, from: &str, to: &str) -> String {
+    let pattern = format!("{}.", from);
+    let mut out = String::with_capacity(fragment.len());
+    let mut quote: Option<char> = None;
+    let mut chars = fragment.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if let Some(q) = quote {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next().map(|(_, escaped)| escaped)),
+                _ if c == q && chars.peek().is_some_and(|&(_, next)| next == q) => {
+                    out.extend(chars.next().map(|(_, doubled)| doubled))
+                }
+                _ if c == q => quote = None,
+                _ => {}
+            }
+            continue;
+        }
+        let before = out.chars().next_back();
+        let is_reference = fragment[i..].starts_with(&pattern)
+            && !before.is_some_and(|b| b.is_alphanumeric() || b == '_' || b == '.' || b == '`');
+        match is_reference {
+            true => {
+                out.push_str(to);
+                out.push('.');
+                for _ in 1..pattern.chars().count() {
+                    chars.next();
+                }
+            }
+            false => {
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+/// Renders `value` as a single-quoted SQL string literal, escaping quotes
+/// and backslashes.
+pub fn quote_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => out.push_str("''"),
+            '\\' => out.push_str("\\\\"),
+            _ => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+/// Renders `bytes` as a hexadecimal literal such as `X'DEADBEEF'`.
+pub fn hex_literal(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2 + 3);
+    out.push_str("X'");
+    for byte in bytes {
+        out.push_str(&format!("{:02X}", byte));
+    }
+    out.push('\'');
+    out
+}
+
+/// Collapses every run of whitespace outside quoted text into one space and
+/// trims the ends, giving a stable form for snapshot tests and log diffs.
+pub fn normalize_sql(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut quote: Option<char> = None;
+    let mut pending_space = false;
+    for c in sql.chars() {
+        if quote.is_none() && c.is_whitespace() {
+            pending_space = !out.is_empty();
+            continue;
+        }
+        if pending_space {
+            out.push(' ');
+            pending_space = false;
+        }
+        match quote {
+            Some(q) if c == q => quote = None,
+            None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+            _ => {}
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Turns `sql` into a `PREPARE ... EXECUTE ... USING` script for plain MySQL
+/// clients: every quoted string literal becomes a `?` bound to `@p1`, `@p2`, ...
+pub fn prepare_script(name: &str, sql: &str) -> String {
+    let mut statement = String::with_capacity(sql.len());
+    let mut parameters: Vec<String> = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if !statement.ends_with(['X', 'x', 'B', 'b']) => {
+                let mut literal = String::from("'");
+                while let Some(c) = chars.next() {
+                    literal.push(c);
+                    match c {
+                        '\\' => literal.extend(chars.next()),
+                        '\'' if chars.peek() == Some(&'\'') => literal.extend(chars.next()),
+                        '\'' => break,
+                        _ => {}
+                    }
+                }
+                parameters.push(literal);
+                statement.push('?');
+            }
+            '\'' | '`' | '"' => {
+                statement.push(c);
+                for inner in chars.by_ref() {
+                    statement.push(inner);
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            _ => statement.push(c),
+        }
+    }
+    let mut script = format!("PREPARE {} FROM {};\n", name, quote_literal(&statement));
+    let mut variables = Vec::new();
+    for (i, parameter) in parameters.iter().enumerate() {
+        script = format!("{}SET @p{} = {};\n", script, i + 1, parameter);
+        variables.push(format!("@p{}", i + 1));
+    }
+    match variables.is_empty() {
+        true => script = format!("{}EXECUTE {};\n", script, name),
+        false => {
+            script = format!(
+                "{}EXECUTE {} USING {};\n",
+                script,
+                name,
+                variables.join(", ")
+            )
+        }
+    }
+    format!("{}DEALLOCATE PREPARE {};", script, name)
+}
+
+/// Whether a fragment calls one of MySQL's common aggregate functions.
+#[doc(hidden)]
+pub fn contains_aggregate(fragment: &str) -> bool {
+    let upper = fragment.to_uppercase();
+    ["COUNT(", "SUM(", "AVG(", "MIN(", "MAX(", "GROUP_CONCAT("]
+        .iter()
+        .any(|function| upper.contains(function))
+}
+
+/// Aliases introduced with `AS` at the top level of a fragment, ignoring
+/// parenthesized subqueries and quoted text.
+#[doc(hidden)]
+pub fn aliases(fragment: &str) -> Vec<String> {
+    let mut aliases = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut previous = ' ';
+    let chars: Vec<char> = fragment.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+        } else {
+            match c {
+                '\'' | '"' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                'A' | 'a'
+                    if depth == 0
+                        && (previous.is_whitespace() || previous == ')')
+                        && chars
+                            .get(i + 1)
+                            .is_some_and(|n| n.eq_ignore_ascii_case(&'s'))
+                        && chars.get(i + 2).is_some_and(|n| n.is_whitespace()) =>
+                {
+                    let alias: String = chars[i + 2..]
+                        .iter()
+                        .skip_while(|n| n.is_whitespace())
+                        .take_while(|n| n.is_alphanumeric() || **n == '_' || **n == '`')
+                        .filter(|n| **n != '`')
+                        .collect();
+                    if !alias.is_empty() {
+                        aliases.push(alias);
+                    }
+                    i += 2;
+                    previous = ' ';
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        previous = c;
+        i += 1;
+    }
+    aliases
+}
+
+/// ORDER BY terms a `SELECT DISTINCT` projection neither selects nor
+/// aliases, which MySQL rejects. Always empty for other projections.
+#[doc(hidden)]
+pub fn unselected_order_terms(selected: &str, order_by: &[String]) -> Vec<String> {
+    let selected = selected.trim_start();
+    let Some(projection) = selected
+        .get(..9)
+        .filter(|keyword| keyword.eq_ignore_ascii_case("DISTINCT "))
+        .map(|_| selected[9..].to_lowercase())
+    else {
+        return Vec::new();
+    };
+    let aliases: Vec<String> = aliases(&projection);
+    let expressions: Vec<&str> = split_top_level(&projection)
+        .into_iter()
+        .map(|item| item.rfind(" as ").map_or(item, |end| &item[..end]).trim())
+        .collect();
+    let mut terms = Vec::new();
+    for term in order_by.iter().flat_map(|entry| split_top_level(entry)) {
+        let lower = term.to_lowercase();
+        let expression = lower
+            .strip_suffix(" asc")
+            .or_else(|| lower.strip_suffix(" desc"))
+            .unwrap_or(&lower);
+        let expression = expression
+            .find(" collate ")
+            .map_or(expression, |end| &expression[..end])
+            .trim();
+        if !expressions.contains(&expression) && !aliases.iter().any(|alias| alias == expression) {
+            terms.push(term.trim().to_string());
+        }
+    }
+    terms
+}
+
+/// Splits `fragment` at the commas outside parentheses and quoted text.
+fn split_top_level(fragment: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in fragment.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    items.push(fragment[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    items.push(fragment[start..].trim());
+    items
+}
+
+/// Name a joined table is referenced by: its `AS` alias, the word following
+/// the table, or the table itself.
+#[doc(hidden)]
+pub fn join_alias(join: &str) -> Option<String> {
+    if let Some(alias) = aliases(join).pop() {
+        return Some(alias);
+    }
+    let start = join.find("JOIN ")? + "JOIN ".len();
+    let mut words = join[start..].split_whitespace();
+    let table = words.next()?;
+    match words.next() {
+        Some(word) if !word.eq_ignore_ascii_case("ON") && !word.eq_ignore_ascii_case("USING") => {
+            Some(word.to_string())
+        }
+        _ => Some(table.to_string()),
+    }
+}
+
+/// Appends `comments` to `sql` in sqlcommenter format, e.g.
+/// `SELECT 1 /*route='%2Forders',traceparent='00-ab-cd-01'*/`. An empty
+/// statement stays empty.
+#[doc(hidden)]
+pub fn append_comments(sql: String, comments: &BTreeMap<String, String>) -> String {
+    if comments.is_empty() || sql.is_empty() {
+        return sql;
+    }
+    let pairs: Vec<String> = comments
+        .iter()
+        .map(|(key, value)| format!("{}='{}'", url_encode(key), url_encode(value)))
+        .collect();
+    format!("{} /*{}*/", sql, pairs.join(","))
+}
+
+fn url_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_are_not_appended_to_an_empty_statement() {
+        let comments = BTreeMap::from([(String::from("route"), String::from("/orders"))]);
+        assert_eq!(
+            append_comments(String::from("SELECT 1"), &comments),
+            "SELECT 1 /*route='%2Forders'*/"
+        );
+        assert_eq!(append_comments(String::new(), &comments), "");
+    }
+
+    #[test]
+    fn unselected_order_terms_only_checks_distinct_projections() {
+        let order_by = vec![String::from("orders.created_at DESC")];
+        assert!(unselected_order_terms("orders.id", &order_by).is_empty());
+        assert_eq!(
+            unselected_order_terms("DISTINCT orders.id", &order_by),
+            vec!["orders.created_at DESC"]
+        );
+        assert!(
+            unselected_order_terms("DISTINCT orders.id, orders.created_at", &order_by).is_empty()
+        );
+    }
+
+    #[test]
+    fn unselected_order_terms_accepts_aliases_and_split_terms() {
+        let order_by = vec![
+            String::from("total DESC"),
+            String::from("ISNULL(orders.id), orders.id ASC"),
+        ];
+        assert_eq!(
+            unselected_order_terms("DISTINCT orders.id, SUM(orders.amount) AS total", &order_by),
+            vec!["ISNULL(orders.id)"]
+        );
+    }
+
+    #[test]
+    fn normalize_sql_collapses_whitespace_outside_quotes() {
+        assert_eq!(
+            normalize_sql("  SELECT a \nFROM  t\tWHERE b = 'x  \n y' AND `c  d` = 1 \n"),
+            "SELECT a FROM t WHERE b = 'x  \n y' AND `c  d` = 1"
+        );
+    }
+
+    #[test]
+    fn prepare_script_binds_string_literals() {
+        assert_eq!(
+            prepare_script(
+                "q",
+                "SELECT `it's` FROM t WHERE a = 'o''k' AND b = X'FF' AND c = 'x'"
+            ),
+            "PREPARE q FROM 'SELECT `it''s` FROM t WHERE a = ? AND b = X''FF'' AND c = ?';\n\
+             SET @p1 = 'o''k';\n\
+             SET @p2 = 'x';\n\
+             EXECUTE q USING @p1, @p2;\n\
+             DEALLOCATE PREPARE q;"
+        );
+        assert_eq!(
+            prepare_script("q", "SELECT 1"),
+            "PREPARE q FROM 'SELECT 1';\nEXECUTE q;\nDEALLOCATE PREPARE q;"
+        );
+    }
+
+    #[test]
+    fn requalify_renames_qualified_references() {
+        assert_eq!(
+            requalify("orders.id = customers.order_id", "orders", "o"),
+            "o.id = customers.order_id"
+        );
+        assert_eq!(requalify("old_orders.id", "orders", "o"), "old_orders.id");
+    }
+
+    #[test]
+    fn requalify_leaves_literals_alone() {
+        assert_eq!(
+            requalify("orders.status = 'see orders.txt'", "orders", "o"),
+            "o.status = 'see orders.txt'"
+        );
+        assert_eq!(
+            requalify(
+                "orders.a = 'it''s orders.b' AND orders.c = 1",
+                "orders",
+                "o"
+            ),
+            "o.a = 'it''s orders.b' AND o.c = 1"
+        );
+        assert_eq!(
+            requalify(r"orders.a = 'x\' orders.b' AND orders.c = 1", "orders", "o"),
+            r"o.a = 'x\' orders.b' AND o.c = 1"
+        );
+        assert_eq!(
+            requalify("orders.a = \"orders.b\"", "orders", "o"),
+            "o.a = \"orders.b\""
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::sql::{hex_literal, quote_literal};
+
+/// Raw SQL with `:name` placeholders whose values are escaped on build.
+///
+/// Placeholders inside quoted strings and `::` casts are left untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct QueryTemplate {
+    sql: String,
+    bindings: BTreeMap<String, Binding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Binding {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+/// Error returned when a template cannot be rendered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// The template references a placeholder that was never bound.
+    Unbound(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Unbound(name) => write!(f, "placeholder :{} is not bound", name),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+impl QueryTemplate {
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            bindings: BTreeMap::new(),
+        }
+    }
+
+    /// Binds a value to `:name`. Binding the same name again replaces it.
+    pub fn bind(mut self, name: &str, value: impl ToString) -> Self {
+        self.bindings
+            .insert(name.to_string(), Binding::Text(value.to_string()));
+        self
+    }
+
+    /// Binds raw bytes to `:name`, rendered as a hex literal (`X'DEADBEEF'`).
+    /// `build_with_params()` keeps the literal inline instead of emitting `?`.
+    pub fn bind_bytes(mut self, name: &str, value: &[u8]) -> Self {
+        self.bindings
+            .insert(name.to_string(), Binding::Bytes(value.to_vec()));
+        self
+    }
+
+    /// Substitutes every placeholder with its escaped, quoted value.
+    pub fn build(&self) -> Result<String, TemplateError> {
+        self.render(|binding| match binding {
+            Binding::Text(value) => quote_literal(value),
+            Binding::Bytes(bytes) => hex_literal(bytes),
+        })
+    }
+
+    /// Replaces every placeholder with `?` and returns the values in the
+    /// order they appear, for drivers that bind parameters themselves.
+    pub fn build_with_params(&self) -> Result<(String, Vec<String>), TemplateError> {
+        let mut params = Vec::new();
+        let sql = self.render(|binding| match binding {
+            Binding::Text(value) => {
+                params.push(value.to_string());
+                "?".to_string()
+            }
+            Binding::Bytes(bytes) => hex_literal(bytes),
+        })?;
+        Ok((sql, params))
+    }
+
+    fn render(
+        &self,
+        mut substitute: impl FnMut(&Binding) -> String,
+    ) -> Result<String, TemplateError> {
+        let mut out = String::with_capacity(self.sql.len());
+        let mut chars = self.sql.chars().peekable();
+        let mut quote: Option<char> = None;
+        while let Some(c) = chars.next() {
+            if let Some(q) = quote {
+                out.push(c);
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        out.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
+                continue;
+            }
+            match c {
+                '\'' | '"' | '`' => {
+                    quote = Some(c);
+                    out.push(c);
+                }
+                ':' if chars.peek() == Some(&':') => {
+                    out.push_str("::");
+                    chars.next();
+                }
+                ':' if chars.peek().is_some_and(|n| n.is_alphabetic() || *n == '_') => {
+                    let mut name = String::new();
+                    while let Some(&n) = chars.peek() {
+                        if !(n.is_alphanumeric() || n == '_') {
+                            break;
+                        }
+                        name.push(n);
+                        chars.next();
+                    }
+                    match self.bindings.get(&name) {
+                        Some(value) => out.push_str(&substitute(value)),
+                        None => return Err(TemplateError::Unbound(name)),
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_escapes_placeholders_outside_quotes() {
+        let template = QueryTemplate::new(
+            "SELECT ':skip', id::text FROM users WHERE name = :name AND id = :id",
+        )
+        .bind("name", "o'hara")
+        .bind("id", 7);
+        assert_eq!(
+            template.build(),
+            Ok(String::from(
+                "SELECT ':skip', id::text FROM users WHERE name = 'o''hara' AND id = '7'"
+            ))
+        );
+        assert_eq!(
+            template.build_with_params(),
+            Ok((
+                String::from("SELECT ':skip', id::text FROM users WHERE name = ? AND id = ?"),
+                vec![String::from("o'hara"), String::from("7")]
+            ))
+        );
+    }
+
+    #[test]
+    fn bytes_are_inlined_as_hex_literals() {
+        let template = QueryTemplate::new("SELECT * FROM keys WHERE key = :key AND name = :name")
+            .bind_bytes("key", &[0xde, 0xad])
+            .bind("name", "k");
+        assert_eq!(
+            template.build_with_params(),
+            Ok((
+                String::from("SELECT * FROM keys WHERE key = X'DEAD' AND name = ?"),
+                vec![String::from("k")]
+            ))
+        );
+    }
+
+    #[test]
+    fn build_reports_unbound_placeholders() {
+        assert_eq!(
+            QueryTemplate::new("SELECT :missing").build(),
+            Err(TemplateError::Unbound(String::from("missing")))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+
+use crate::error::QueryBuildError;
+use crate::sql::quote_literal;
+
+thread_local! {
+    static CURRENT_TENANT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the tenant that `#[tenant_key]` models on this thread are scoped to.
+pub fn set_tenant(tenant: impl ToString) {
+    CURRENT_TENANT.with(|current| *current.borrow_mut() = Some(tenant.to_string()));
+}
+
+/// Unsets the tenant set with [`set_tenant`].
+pub fn clear_tenant() {
+    CURRENT_TENANT.with(|current| *current.borrow_mut() = None);
+}
+
+pub fn current_tenant() -> Option<String> {
+    CURRENT_TENANT.with(|current| current.borrow().clone())
+}
+
+/// Runs `f` with `tenant` as the current tenant, then restores the previous
+/// one, even if `f` panics.
+pub fn with_tenant<R>(tenant: impl ToString, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<String>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_TENANT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore =
+        Restore(CURRENT_TENANT.with(|current| current.replace(Some(tenant.to_string()))));
+    f()
+}
+
+/// Condition restricting `column` to the current tenant, added when the
+/// select, update and delete builders of `#[tenant_key]` models are built.
+///
+/// Fails when no tenant is set, so a query can never silently span tenants.
+#[doc(hidden)]
+pub fn tenant_condition(table: &str, column: &str) -> Result<String, QueryBuildError> {
+    match current_tenant() {
+        Some(tenant) => Ok(format!("{} = {}", column, quote_literal(&tenant))),
+        None => Err(QueryBuildError::NoTenant(table.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_tenant_restores_the_previous_tenant() {
+        set_tenant(1);
+        let inner = with_tenant(2, current_tenant);
+        assert_eq!(inner.as_deref(), Some("2"));
+        assert_eq!(current_tenant().as_deref(), Some("1"));
+        clear_tenant();
+        assert_eq!(current_tenant(), None);
+    }
+
+    #[test]
+    fn tenant_condition_quotes_the_tenant() {
+        let condition = with_tenant("o'brien", || tenant_condition("orders", "tenant_id"));
+        assert_eq!(condition.as_deref(), Ok("tenant_id = 'o''brien'"));
+        assert_eq!(
+            tenant_condition("orders", "tenant_id"),
+            Err(QueryBuildError::NoTenant(String::from("orders")))
+        );
+    }
+}
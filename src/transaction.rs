@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// Script running several statements in one MySQL transaction:
+/// `START TRANSACTION; ...; COMMIT;`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Transaction {
+    statements: Vec<String>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a statement, typically the output of a generated builder.
+    pub fn statement(mut self, sql: impl Into<String>) -> Self {
+        let sql = sql.into();
+        self.statements
+            .push(sql.trim_end().trim_end_matches(';').to_string());
+        self
+    }
+
+    /// Marks a point the transaction can later be rolled back to.
+    pub fn savepoint(self, name: &str) -> Self {
+        self.statement(format!("SAVEPOINT {}", name))
+    }
+
+    /// Undoes the statements run after `savepoint(name)`, keeping the
+    /// transaction open.
+    pub fn rollback_to(self, name: &str) -> Self {
+        self.statement(format!("ROLLBACK TO SAVEPOINT {}", name))
+    }
+
+    pub fn release(self, name: &str) -> Self {
+        self.statement(format!("RELEASE SAVEPOINT {}", name))
+    }
+
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Transaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "START TRANSACTION;")?;
+        for statement in &self.statements {
+            writeln!(f, "{};", statement)?;
+        }
+        write!(f, "COMMIT;")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn savepoints_are_statements_of_the_transaction() {
+        let transaction = Transaction::new()
+            .statement("INSERT INTO a VALUES (1);")
+            .savepoint("before_b")
+            .statement("INSERT INTO b VALUES (2)\n")
+            .rollback_to("before_b")
+            .release("before_b");
+        assert_eq!(
+            transaction.build(),
+            "START TRANSACTION;\n\
+             INSERT INTO a VALUES (1);\n\
+             SAVEPOINT before_b;\n\
+             INSERT INTO b VALUES (2);\n\
+             ROLLBACK TO SAVEPOINT before_b;\n\
+             RELEASE SAVEPOINT before_b;\n\
+             COMMIT;"
+        );
+    }
+}
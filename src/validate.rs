@@ -0,0 +1,41 @@
+use crate::QueryBuildError;
+
+/// Parses `sql` with the MySQL dialect of `sqlparser` when the `validate`
+/// feature is enabled; without the feature every statement is accepted.
+pub fn check_syntax(sql: &str) -> Result<(), QueryBuildError> {
+    parse(sql)
+}
+
+#[cfg(feature = "validate")]
+fn parse(sql: &str) -> Result<(), QueryBuildError> {
+    use sqlparser::dialect::MySqlDialect;
+    use sqlparser::parser::Parser;
+
+    Parser::parse_sql(&MySqlDialect {}, sql)
+        .map(|_| ())
+        .map_err(|error| QueryBuildError::InvalidSql(error.to_string()))
+}
+
+#[cfg(not(feature = "validate"))]
+fn parse(_sql: &str) -> Result<(), QueryBuildError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_sql() {
+        assert_eq!(check_syntax("SELECT id FROM orders WHERE id = '1'"), Ok(()));
+    }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    fn rejects_invalid_sql() {
+        assert!(matches!(
+            check_syntax("SELECT FROM WHERE"),
+            Err(QueryBuildError::InvalidSql(_))
+        ));
+    }
+}
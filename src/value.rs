@@ -0,0 +1,196 @@
+use crate::sql::{hex_literal, quote_literal};
+
+/// Literal for a value of a regular field: its text in single quotes, with
+/// quotes and backslashes escaped.
+#[doc(hidden)]
+pub fn literal<T: ToString + ?Sized>(value: &T) -> String {
+    quote_literal(&value.to_string())
+}
+
+/// Values accepted by setters and conditions of byte fields (`Vec<u8>`,
+/// `[u8; N]`), rendered as hexadecimal literals such as `X'DEADBEEF'`.
+pub trait BinaryValue {
+    fn sql_literal(&self) -> String;
+}
+
+impl BinaryValue for [u8] {
+    fn sql_literal(&self) -> String {
+        hex_literal(self)
+    }
+}
+
+impl BinaryValue for Vec<u8> {
+    fn sql_literal(&self) -> String {
+        hex_literal(self)
+    }
+}
+
+impl<const N: usize> BinaryValue for [u8; N] {
+    fn sql_literal(&self) -> String {
+        hex_literal(self)
+    }
+}
+
+impl<T: BinaryValue> BinaryValue for Option<T> {
+    fn sql_literal(&self) -> String {
+        match self {
+            Some(value) => value.sql_literal(),
+            None => String::from("NULL"),
+        }
+    }
+}
+
+impl<T: BinaryValue + ?Sized> BinaryValue for &T {
+    fn sql_literal(&self) -> String {
+        (**self).sql_literal()
+    }
+}
+
+/// Tuples accepted by `where_tuple_in`, one element per column, each
+/// rendered like the value of a regular field.
+pub trait RowValue {
+    fn sql_literals(&self) -> Vec<String>;
+}
+
+macro_rules! row_value {
+    ($($name:ident),+) => {
+        impl<$($name: ToString),+> RowValue for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn sql_literals(&self) -> Vec<String> {
+                let ($($name,)+) = self;
+                vec![$(literal($name)),+]
+            }
+        }
+    };
+}
+
+row_value!(A, B);
+row_value!(A, B, C);
+row_value!(A, B, C, D);
+
+/// Values accepted by setters and conditions of `chrono` date/time fields,
+/// rendered in the format MySQL expects for DATETIME, DATE and TIME.
+/// `DateTime<Tz>` values are stored in UTC.
+#[cfg(feature = "chrono")]
+pub trait DateTimeValue {
+    fn sql_literal(&self) -> String;
+}
+
+#[cfg(feature = "chrono")]
+mod datetime {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+    use super::DateTimeValue;
+    use crate::sql::quote_literal;
+
+    impl DateTimeValue for NaiveDateTime {
+        fn sql_literal(&self) -> String {
+            format!("'{}'", self.format("%Y-%m-%d %H:%M:%S%.f"))
+        }
+    }
+
+    impl<Tz: TimeZone> DateTimeValue for DateTime<Tz> {
+        fn sql_literal(&self) -> String {
+            self.naive_utc().sql_literal()
+        }
+    }
+
+    impl DateTimeValue for NaiveDate {
+        fn sql_literal(&self) -> String {
+            format!("'{}'", self.format("%Y-%m-%d"))
+        }
+    }
+
+    impl DateTimeValue for NaiveTime {
+        fn sql_literal(&self) -> String {
+            format!("'{}'", self.format("%H:%M:%S%.f"))
+        }
+    }
+
+    /// Already formatted text such as `"2024-01-01 10:00:00"`.
+    impl DateTimeValue for str {
+        fn sql_literal(&self) -> String {
+            quote_literal(self)
+        }
+    }
+
+    impl DateTimeValue for String {
+        fn sql_literal(&self) -> String {
+            quote_literal(self)
+        }
+    }
+
+    impl<T: DateTimeValue> DateTimeValue for Option<T> {
+        fn sql_literal(&self) -> String {
+            match self {
+                Some(value) => value.sql_literal(),
+                None => String::from("NULL"),
+            }
+        }
+    }
+
+    impl<T: DateTimeValue + ?Sized> DateTimeValue for &T {
+        fn sql_literal(&self) -> String {
+            (**self).sql_literal()
+        }
+    }
+}
+
+/// Values accepted by setters and conditions of `uuid::Uuid` fields.
+/// `#[uuid_binary]` fields render them through [`UuidValue::binary_literal`].
+#[cfg(feature = "uuid")]
+pub trait UuidValue {
+    fn sql_literal(&self) -> String;
+
+    /// The value converted with `UUID_TO_BIN()` for `BINARY(16)` columns.
+    fn binary_literal(&self) -> String {
+        let literal = self.sql_literal();
+        if literal == "NULL" {
+            literal
+        } else {
+            format!("UUID_TO_BIN({})", literal)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_value {
+    use uuid::Uuid;
+
+    use super::UuidValue;
+    use crate::sql::quote_literal;
+
+    impl UuidValue for Uuid {
+        fn sql_literal(&self) -> String {
+            format!("'{}'", self.hyphenated())
+        }
+    }
+
+    /// Already formatted text such as `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+    impl UuidValue for str {
+        fn sql_literal(&self) -> String {
+            quote_literal(self)
+        }
+    }
+
+    impl UuidValue for String {
+        fn sql_literal(&self) -> String {
+            quote_literal(self)
+        }
+    }
+
+    impl<T: UuidValue> UuidValue for Option<T> {
+        fn sql_literal(&self) -> String {
+            match self {
+                Some(value) => value.sql_literal(),
+                None => String::from("NULL"),
+            }
+        }
+    }
+
+    impl<T: UuidValue + ?Sized> UuidValue for &T {
+        fn sql_literal(&self) -> String {
+            (**self).sql_literal()
+        }
+    }
+}
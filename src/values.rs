@@ -0,0 +1,66 @@
+use std::fmt;
+
+use crate::sql::quote_literal;
+
+/// MySQL 8 table value constructor: `VALUES ROW('1', 'a'), ROW('2', 'b')`.
+/// Use `as_table()` to join a query against an in-memory list of tuples.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Values {
+    rows: Vec<Vec<String>>,
+}
+
+impl Values {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one row; every value is rendered as an escaped string literal.
+    pub fn row<T: ToString>(mut self, values: &[T]) -> Self {
+        self.rows.push(
+            values
+                .iter()
+                .map(|value| quote_literal(&value.to_string()))
+                .collect(),
+        );
+        self
+    }
+
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+
+    /// Derived table fragment `(VALUES ROW(...)) AS alias (column, ...)` for
+    /// `join_subquery()` or a CTE body.
+    pub fn as_table(&self, alias: &str, columns: &[&str]) -> String {
+        match columns.is_empty() {
+            true => format!("({}) AS {}", self, alias),
+            false => format!("({}) AS {} ({})", self, alias, columns.join(", ")),
+        }
+    }
+}
+
+impl fmt::Display for Values {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| format!("ROW({})", row.join(", ")))
+            .collect();
+        write!(f, "VALUES {}", rows.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_render_as_a_derived_table() {
+        let values = Values::new().row(&[1, 2]).row(&["o'k", "b"]);
+        assert_eq!(values.build(), "VALUES ROW('1', '2'), ROW('o''k', 'b')");
+        assert_eq!(
+            values.as_table("v", &["id", "name"]),
+            "(VALUES ROW('1', '2'), ROW('o''k', 'b')) AS v (id, name)"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::order::Order;
+
+/// Window specification rendered inside `OVER (...)` by the select
+/// builder's `select_window_as()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Window {
+    partition_by: Vec<String>,
+    order_by: Vec<String>,
+    frame: Option<String>,
+}
+
+/// One end of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameBound::UnboundedPreceding => f.write_str("UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(n) => write!(f, "{} PRECEDING", n),
+            FrameBound::CurrentRow => f.write_str("CURRENT ROW"),
+            FrameBound::Following(n) => write!(f, "{} FOLLOWING", n),
+            FrameBound::UnboundedFollowing => f.write_str("UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl Window {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn partition_by(mut self, expression: &str) -> Self {
+        self.partition_by.push(expression.to_string());
+        self
+    }
+
+    pub fn order_by(mut self, expression: &str, order: Order) -> Self {
+        self.order_by.push(format!("{} {}", expression, order));
+        self
+    }
+
+    /// Frame counted in rows, e.g. `ROWS BETWEEN 6 PRECEDING AND CURRENT ROW`
+    /// for a seven-row moving window.
+    pub fn rows_between(self, start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            frame: Some(format!("ROWS BETWEEN {} AND {}", start, end)),
+            ..self
+        }
+    }
+
+    /// Frame counted by the ORDER BY value instead of by rows.
+    pub fn range_between(self, start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            frame: Some(format!("RANGE BETWEEN {} AND {}", start, end)),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.partition_by.is_empty() {
+            parts.push(format!("PARTITION BY {}", self.partition_by.join(", ")));
+        }
+        if !self.order_by.is_empty() {
+            parts.push(format!("ORDER BY {}", self.order_by.join(", ")));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.clone());
+        }
+        f.write_str(&parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_partition_order_and_frame() {
+        let window = Window::new()
+            .partition_by("customer_id")
+            .order_by("created_at", Order::Asc)
+            .rows_between(FrameBound::Preceding(6), FrameBound::CurrentRow);
+        assert_eq!(
+            window.to_string(),
+            "PARTITION BY customer_id ORDER BY created_at ASC ROWS BETWEEN 6 PRECEDING AND CURRENT ROW"
+        );
+        assert_eq!(Window::new().to_string(), "");
+    }
+}
@@ -0,0 +1,262 @@
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("audit_log")]
+#[table(ops(select, insert))]
+struct AuditEntry {
+    id: u64,
+    message: String,
+}
+
+#[test]
+fn ops_generates_only_the_requested_builders() {
+    assert_eq!(
+        AuditEntry::select().build_compact(),
+        "SELECT audit_log.id, audit_log.message FROM audit_log"
+    );
+    assert_eq!(
+        AuditEntry::insert().insert_to_message("x").build_compact(),
+        "INSERT INTO audit_log (message) VALUES ('x')"
+    );
+}
+
+mod nested {
+    use prkorm::Table;
+
+    #[derive(Table)]
+    #[table_name("sessions")]
+    #[table(vis = "pub(crate)", module)]
+    pub(crate) struct Session {
+        id: u64,
+    }
+}
+
+#[test]
+fn module_nests_the_generated_items() {
+    assert_eq!(
+        nested::Session::select().build_compact(),
+        "SELECT sessions.id FROM sessions"
+    );
+    let column: nested::session_query::SessionColumn = nested::session_query::SessionColumn::Id;
+    assert_eq!(column.name(), "id");
+}
+
+mod drafts {
+    use prkorm::Table;
+
+    #[derive(Table)]
+    #[table_name("drafts")]
+    #[table(vis = "", module)]
+    struct Draft {
+        id: u64,
+    }
+
+    mod notes {
+        use prkorm::Table;
+
+        #[derive(Table)]
+        #[table_name("notes")]
+        #[table(vis = "pub(super)", module)]
+        pub(super) struct Note {
+            id: u64,
+        }
+    }
+
+    pub(super) fn queries() -> [String; 2] {
+        [
+            Draft::select().build_compact(),
+            notes::Note::select().build_compact(),
+        ]
+    }
+}
+
+#[test]
+fn module_keeps_restricted_visibility_reachable() {
+    assert_eq!(
+        drafts::queries(),
+        ["SELECT drafts.id FROM drafts", "SELECT notes.id FROM notes"]
+    );
+}
+
+#[derive(Table)]
+#[table_name("products")]
+#[table(
+    select_builder = "ProductQuery",
+    insert_builder = "NewProduct",
+    update_builder = "ProductChanges",
+    delete_builder = "ProductRemoval",
+    column_enum = "ProductField"
+)]
+struct Product {
+    id: u64,
+    sku: String,
+}
+
+#[test]
+fn builders_and_column_enum_can_be_renamed() {
+    let query: ProductQuery = Product::select();
+    let _: NewProduct = Product::insert();
+    let _: ProductChanges = Product::update();
+    let _: ProductRemoval = Product::delete();
+    assert_eq!(
+        query
+            .order_by_column(ProductField::Sku, prkorm::Order::Asc)
+            .build_compact(),
+        "SELECT products.id, products.sku FROM products ORDER BY products.sku ASC"
+    );
+}
+
+#[derive(Table)]
+struct LineItem {
+    id: u64,
+}
+
+#[derive(Table)]
+#[table(convention = "lowercase", pluralize)]
+struct OrderCategory {
+    id: u64,
+}
+
+#[test]
+fn table_name_is_inferred_from_the_struct() {
+    assert_eq!(LineItem::table(), "line_item");
+    assert_eq!(OrderCategory::table(), "ordercategories");
+}
+
+#[derive(Table)]
+#[table_name("logs")]
+#[table(default_limit = 100)]
+struct LogLine {
+    id: u64,
+}
+
+#[test]
+fn default_limit_applies_until_overridden() {
+    assert_eq!(
+        LogLine::select().build_compact(),
+        "SELECT logs.id FROM logs LIMIT 100"
+    );
+    assert_eq!(
+        LogLine::select().limit(5).build_compact(),
+        "SELECT logs.id FROM logs LIMIT 5"
+    );
+}
+
+#[derive(Table)]
+#[table_name("users")]
+#[default_select("id", "email")]
+struct User {
+    id: u64,
+    email: String,
+    #[select_only]
+    last_seen: String,
+    bio: String,
+    #[never_select]
+    password_hash: String,
+}
+
+#[test]
+fn select_only_fields_have_no_setters() {
+    assert_eq!(
+        User::insert()
+            .insert_to_email("a@b.c")
+            .insert_to_password_hash("x")
+            .build_compact(),
+        "INSERT INTO users (email, password_hash) VALUES ('a@b.c', 'x')"
+    );
+    assert!(User::select_all_columns()
+        .build_compact()
+        .contains("users.last_seen"));
+}
+
+#[test]
+fn never_select_fields_stay_out_of_projections() {
+    assert_eq!(
+        User::select_all_columns().build_compact(),
+        "SELECT users.id, users.email, users.last_seen, users.bio FROM users"
+    );
+    assert_eq!(
+        <User as prkorm::Model>::model_columns(),
+        ["id", "email", "last_seen", "bio"]
+    );
+}
+
+#[test]
+fn filters_cannot_name_never_select_fields() {
+    let mut filter = prkorm::Filter::new();
+    filter.conditions.push((
+        String::from("password_hash"),
+        prkorm::FilterOp::Like(String::from("a%")),
+    ));
+    assert_eq!(
+        User::select().apply_filter(&filter),
+        Err(prkorm::FilterError::UnknownColumn(String::from(
+            "password_hash"
+        )))
+    );
+    assert_eq!(
+        User::select().order_by_param("password_hash", "asc"),
+        Err(prkorm::FilterError::UnknownColumn(String::from(
+            "password_hash"
+        )))
+    );
+}
+
+#[test]
+fn default_select_narrows_select() {
+    assert_eq!(
+        User::select().where_id(1u64).build_compact(),
+        "SELECT users.id, users.email FROM users WHERE users.id = '1'"
+    );
+}
+
+#[derive(Table)]
+#[table_name("prices")]
+#[table(engine = "InnoDB", comment = "Price list")]
+struct Price {
+    id: u64,
+    #[column(sql_type = "DECIMAL(12,2)", comment = "Net amount")]
+    amount: String,
+    note: Option<String>,
+}
+
+#[test]
+fn create_table_sql_uses_field_types_and_options() {
+    assert_eq!(
+        Price::create_table_sql(),
+        "CREATE TABLE prices (\n  \
+         id BIGINT UNSIGNED NOT NULL,\n  \
+         amount DECIMAL(12,2) NOT NULL COMMENT 'Net amount',\n  \
+         note VARCHAR(255) NULL\n\
+         ) ENGINE=InnoDB COMMENT='Price list'"
+    );
+}
+
+#[derive(Table)]
+#[table_name("contacts")]
+#[table_schema("crm")]
+struct Contact {
+    id: u64,
+    name: String,
+}
+
+#[test]
+fn table_schema_qualifies_the_table() {
+    assert_eq!(Contact::qualified_table(), "crm.contacts");
+    assert_eq!(
+        Contact::select().where_id(1u64).build_compact(),
+        "SELECT contacts.id, contacts.name FROM crm.contacts contacts WHERE contacts.id = '1'"
+    );
+    assert_eq!(
+        Contact::delete().delete_where_id_eq(1u64),
+        "DELETE FROM crm.contacts WHERE id = '1'"
+    );
+    assert_eq!(
+        User::select()
+            .left_join_by_id(&Contact::qualified_table(), "id")
+            .build_compact(),
+        "SELECT users.id, users.email FROM users LEFT JOIN crm.contacts ON crm.contacts.id = users.id"
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "chrono")]
+#![allow(dead_code)]
+
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("events")]
+struct Event {
+    id: u64,
+    starts_at: NaiveDateTime,
+    ends_on: Option<NaiveDate>,
+}
+
+#[test]
+fn date_time_values_render_as_mysql_literals() {
+    let starts_at = NaiveDate::from_ymd_opt(2024, 3, 1)
+        .unwrap()
+        .and_hms_milli_opt(9, 30, 0, 250)
+        .unwrap();
+    assert_eq!(
+        Event::select().where_starts_at(starts_at).build_compact(),
+        "SELECT events.id, events.starts_at, events.ends_on FROM events WHERE events.starts_at = '2024-03-01 09:30:00.250'"
+    );
+    assert_eq!(
+        Event::insert()
+            .insert_to_starts_at(Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap())
+            .insert_to_ends_on(None::<NaiveDate>)
+            .build_compact(),
+        "INSERT INTO events (ends_on, starts_at) VALUES (NULL, '2024-03-01 09:30:00')"
+    );
+    assert_eq!(
+        Event::delete().delete_where_starts_at_lt("2024-01-01 00:00:00"),
+        "DELETE FROM events WHERE starts_at < '2024-01-01 00:00:00'"
+    );
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use prkorm::{Table, Unit};
+
+#[derive(Table)]
+#[table_name("sessions")]
+struct Session {
+    id: u32,
+    #[datetime]
+    expires_at: String,
+}
+
+#[test]
+fn datetime_fields_get_interval_helpers() {
+    assert_eq!(
+        Session::select()
+            .where_expires_at_older_than_interval(30, Unit::Day)
+            .build_compact(),
+        "SELECT sessions.id, sessions.expires_at FROM sessions WHERE sessions.expires_at < NOW() - INTERVAL 30 DAY"
+    );
+    assert_eq!(
+        Session::update()
+            .update_expires_at_with_interval_add(1, Unit::Hour)
+            .update_where_id_eq(7),
+        "UPDATE sessions SET expires_at = expires_at + INTERVAL 1 HOUR \nWHERE id = '7'"
+    );
+}
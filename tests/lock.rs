@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use prkorm::{LockTables, Table};
+
+#[derive(Table)]
+#[table_name("contacts")]
+#[table_schema("crm")]
+struct Contact {
+    id: u32,
+}
+
+#[derive(Table)]
+#[table_name("orders")]
+struct OrderModel {
+    id: u32,
+}
+
+#[test]
+fn model_locks_use_the_model_naming() {
+    assert_eq!(
+        Contact::lock_write().read_model::<OrderModel>().build(),
+        "LOCK TABLES crm.contacts WRITE, orders READ"
+    );
+    assert_eq!(
+        OrderModel::lock_read().write("audit_log").build(),
+        "LOCK TABLES orders READ, audit_log WRITE"
+    );
+    assert_eq!(LockTables::unlock(), "UNLOCK TABLES");
+}
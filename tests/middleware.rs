@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use prkorm::{add_query_middleware, QueryParts, Table, TableNaming};
+
+#[derive(Table)]
+#[table_name("events")]
+struct Event {
+    id: u64,
+    name: String,
+}
+
+static BUILT: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn audit(parts: &mut QueryParts) {
+    parts.naming = parts.naming.clone().with_prefix("app_");
+    parts.conditions.push(String::from("deleted = 0"));
+    parts
+        .comments
+        .insert(String::from("route"), String::from("events"));
+}
+
+fn record(sql: &str) {
+    BUILT.lock().unwrap().push(sql.to_string());
+}
+
+#[test]
+fn every_output_path_runs_the_middleware() {
+    add_query_middleware(audit);
+    let query = Event::select().where_id(7u64);
+    assert_eq!(
+        query.to_string(),
+        "SELECT events.id, events.name \nFROM app_events events \nWHERE (events.id = '7') AND deleted = 0 /*route='events'*/"
+    );
+    assert_eq!(
+        query.build_normalized(),
+        "SELECT events.id, events.name FROM app_events events WHERE (events.id = '7') AND deleted = 0 /*route='events'*/"
+    );
+    assert!(query
+        .build_prepared("events_by_id")
+        .starts_with("PREPARE events_by_id FROM 'SELECT events.id, events.name FROM app_events events WHERE (events.id = ?) AND deleted = 0';"));
+    assert_eq!(
+        query.as_subquery("recent"),
+        "(SELECT events.id, events.name FROM app_events events WHERE (events.id = '7') AND deleted = 0 /*route='events'*/) AS recent"
+    );
+    assert_eq!(
+        Event::insert().insert_to_name("launch").to_string(),
+        "INSERT INTO app_events\n(name) VALUES  ('launch') /*route='events'*/"
+    );
+
+    // Display notifies observers like `build()`; Debug does not.
+    let observed = query.on_build(record);
+    let _ = format!("{:?}", observed);
+    assert!(BUILT.lock().unwrap().is_empty());
+    let sql = observed.to_string();
+    assert_eq!(*BUILT.lock().unwrap(), [sql]);
+}
+
+#[test]
+fn table_naming_resolves_like_the_builders() {
+    let naming = TableNaming::default()
+        .with_prefix("app_")
+        .with_schema("analytics")
+        .with_suffix("_2");
+    assert_eq!(naming.resolve("events"), "analytics.app_events_2");
+    assert_eq!(
+        naming.resolve_as("users", "users"),
+        "analytics.app_users users"
+    );
+}
@@ -0,0 +1,227 @@
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("orders")]
+#[primary_key("id")]
+struct OrderModel {
+    id: u32,
+    customer_id: u32,
+    order_status: String,
+}
+
+#[test]
+fn column_enum_names_the_fields() {
+    let column = OrderModelColumn::CustomerId;
+    assert_eq!(column.name(), "customer_id");
+    assert_eq!(column.as_str(), "orders.customer_id");
+    assert_eq!(column.to_string(), "orders.customer_id");
+    assert_eq!(
+        OrderModelColumn::from_name("order_status").map(|column| column.name()),
+        Some("order_status")
+    );
+    assert!(OrderModelColumn::from_name("total").is_none());
+    assert_eq!(
+        OrderModel::select()
+            .order_by_column(column, prkorm::Order::Desc)
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders ORDER BY orders.customer_id DESC"
+    );
+}
+
+#[test]
+fn column_constants_hold_the_qualified_columns() {
+    assert_eq!(OrderModel::COL_ORDER_STATUS, "orders.order_status");
+    assert_eq!(OrderModel::COL_ID, "orders.id");
+}
+
+#[test]
+fn column_lists_follow_field_order() {
+    assert_eq!(OrderModel::columns(), ["id", "customer_id", "order_status"]);
+    assert_eq!(
+        OrderModel::qualified_columns(),
+        ["orders.id", "orders.customer_id", "orders.order_status"]
+    );
+    assert_eq!(OrderModel::field_count(), 3);
+}
+
+#[test]
+fn show_statements_name_the_table() {
+    assert_eq!(OrderModel::show_columns(), "SHOW COLUMNS FROM orders");
+    assert_eq!(OrderModel::show_create_table(), "SHOW CREATE TABLE orders");
+    assert_eq!(OrderModel::show_indexes(), "SHOW INDEXES FROM orders");
+}
+
+#[test]
+fn introspection_queries_filter_on_the_current_database() {
+    assert_eq!(
+        OrderModel::introspect_table_size_query(),
+        "SELECT TABLE_ROWS, DATA_LENGTH, INDEX_LENGTH, DATA_LENGTH + INDEX_LENGTH AS TOTAL_LENGTH \nFROM information_schema.TABLES \nWHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'orders'"
+    );
+    assert!(OrderModel::introspect_columns_query()
+        .contains("FROM information_schema.COLUMNS \nWHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'orders'"));
+    assert!(OrderModel::introspect_indexes_query().ends_with("ORDER BY INDEX_NAME, SEQ_IN_INDEX"));
+}
+
+#[test]
+fn find_by_primary_key_limits_to_one_row() {
+    assert_eq!(
+        OrderModel::find_by_primary_key(7u32).build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.id = '7' LIMIT 1"
+    );
+}
+
+#[test]
+fn find_by_field_returns_a_single_row_builder() {
+    assert_eq!(
+        OrderModel::find_by_order_status("new").build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.order_status = 'new' LIMIT 1"
+    );
+}
+
+#[test]
+fn exists_by_field_wraps_the_query_in_exists() {
+    assert_eq!(
+        OrderModel::exists_by_customer_id(3u32),
+        "SELECT EXISTS(SELECT 1 FROM orders WHERE orders.customer_id = '3')"
+    );
+}
+
+#[test]
+fn count_by_field_and_count_drop_order_and_limit() {
+    assert_eq!(
+        OrderModel::count_by_order_status("new"),
+        "SELECT COUNT(*) \nFROM orders  \nWHERE orders.order_status = 'new'"
+    );
+    assert_eq!(
+        OrderModel::select_str("customer_id")
+            .group_by_customer_id()
+            .order_by_id_desc()
+            .limit(5)
+            .count(),
+        "SELECT COUNT(*) \nFROM (SELECT customer_id \nFROM orders  \nGROUP BY orders.customer_id) AS counted"
+    );
+}
+
+#[test]
+fn aggregate_constructors_select_one_expression() {
+    assert_eq!(
+        OrderModel::select_max_of_id().build_compact(),
+        "SELECT MAX(orders.id) FROM orders"
+    );
+    assert_eq!(
+        OrderModel::select_avg_of_customer_id_as("average")
+            .where_order_status("new")
+            .build_compact(),
+        "SELECT AVG(orders.customer_id) AS average FROM orders WHERE orders.order_status = 'new'"
+    );
+}
+
+#[test]
+fn views_are_created_from_select_builders() {
+    let pending = OrderModel::select().where_order_status("pending");
+    assert_eq!(
+        OrderModel::create_view_sql("pending_orders", &pending),
+        format!("CREATE VIEW pending_orders AS {}", pending.build())
+    );
+    assert!(
+        OrderModel::create_or_replace_view_sql("pending_orders", &pending)
+            .starts_with("CREATE OR REPLACE VIEW pending_orders AS SELECT ")
+    );
+}
+
+#[test]
+fn create_table_as_copies_a_select() {
+    assert_eq!(
+        OrderModel::create_table_as("orders_2024", &OrderModel::select_id()),
+        "CREATE TABLE orders_2024 AS SELECT orders.id \nFROM orders "
+    );
+}
+
+#[test]
+fn advisory_lock_helpers_quote_the_name() {
+    assert_eq!(
+        OrderModel::get_lock_sql("orders:it's", 10),
+        "SELECT GET_LOCK('orders:it''s', 10)"
+    );
+    assert_eq!(
+        OrderModel::release_lock_sql("orders"),
+        "SELECT RELEASE_LOCK('orders')"
+    );
+}
+
+#[derive(Table)]
+#[table_name("settings")]
+#[primary_key("key")]
+struct Setting {
+    key: String,
+    value: String,
+    order: u32,
+}
+
+#[test]
+fn reserved_word_columns_are_backtick_quoted() {
+    assert_eq!(
+        Setting::select()
+            .where_key("theme")
+            .order_by_order_asc()
+            .build_compact(),
+        "SELECT settings.`key`, settings.value, settings.`order` FROM settings WHERE settings.`key` = 'theme' ORDER BY settings.`order` ASC"
+    );
+    assert_eq!(
+        Setting::insert()
+            .insert_to_key("theme")
+            .insert_to_order(1u32)
+            .build_compact(),
+        "INSERT INTO settings (`key`, `order`) VALUES ('theme', '1')"
+    );
+    assert_eq!(
+        Setting::update()
+            .update_order_with_value(2u32)
+            .update_where_key_eq("theme"),
+        "UPDATE settings SET `order` = '2' \nWHERE `key` = 'theme'"
+    );
+    assert_eq!(
+        Setting::delete().delete_where_key_eq("theme"),
+        "DELETE FROM settings WHERE `key` = 'theme'"
+    );
+    assert_eq!(
+        Setting::create_table_sql(),
+        "CREATE TABLE settings (\n  \
+         `key` VARCHAR(255) NOT NULL,\n  \
+         value VARCHAR(255) NOT NULL,\n  \
+         `order` INT UNSIGNED NOT NULL,\n  \
+         PRIMARY KEY (`key`)\n\
+         )"
+    );
+}
+
+#[derive(Table)]
+#[table_name("invoices")]
+struct Invoice {
+    #[primary_key]
+    number: String,
+    total: u32,
+}
+
+#[derive(Table)]
+#[table_name("tickets")]
+struct Ticket {
+    #[primary_key]
+    id: u32,
+    title: String,
+}
+
+#[test]
+fn primary_key_can_mark_a_field() {
+    assert_eq!(
+        Ticket::find_by_primary_key(7).build_compact(),
+        "SELECT tickets.id, tickets.title FROM tickets WHERE tickets.id = '7' LIMIT 1"
+    );
+    assert_eq!(Invoice::table_primary_key(), "number");
+    assert_eq!(
+        Invoice::find_by_primary_key(String::from("A-1")).build_compact(),
+        "SELECT invoices.number, invoices.total FROM invoices WHERE invoices.number = 'A-1' LIMIT 1"
+    );
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use prkorm::{add_build_observer, clear_build_observers, Table};
+
+#[derive(Table)]
+#[table_name("events")]
+struct Event {
+    id: u64,
+}
+
+static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn log(sql: &str) {
+    LOGGED.lock().unwrap().push(sql.to_string());
+}
+
+#[test]
+fn observers_receive_the_built_sql() {
+    add_build_observer(log);
+    let sql = Event::select().where_id(1u64).build_compact();
+    clear_build_observers();
+    Event::select().build();
+    assert_eq!(*LOGGED.lock().unwrap(), [sql]);
+}
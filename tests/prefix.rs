@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use prkorm::{set_table_prefix, Table};
+
+#[derive(Table)]
+#[table_name("orders")]
+struct OrderModel {
+    id: u32,
+    customer_id: u32,
+}
+
+#[test]
+fn prefix_renames_tables_but_keeps_their_references() {
+    set_table_prefix("t42_");
+    assert_eq!(
+        OrderModel::select()
+            .join_str("JOIN customers ON customers.id = orders.customer_id")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id FROM t42_orders orders JOIN t42_customers customers ON customers.id = orders.customer_id"
+    );
+    assert_eq!(
+        OrderModel::select()
+            .join_str("JOIN countries c ON c.id = orders.id")
+            .join_str("JOIN shared.regions ON shared.regions.id = orders.id")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id FROM t42_orders orders JOIN countries c ON c.id = orders.id JOIN shared.regions ON shared.regions.id = orders.id"
+    );
+    assert_eq!(
+        OrderModel::delete()
+            .table_prefix("")
+            .delete_where_id_eq(1u32),
+        "DELETE FROM orders WHERE id = '1'"
+    );
+    set_table_prefix("");
+    assert_eq!(
+        OrderModel::select().build_compact(),
+        "SELECT orders.id, orders.customer_id FROM orders"
+    );
+}
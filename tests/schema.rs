@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use prkorm::{set_default_schema, Table};
+
+#[derive(Table)]
+#[table_name("orders")]
+struct OrderModel {
+    id: u32,
+}
+
+#[test]
+fn default_schema_qualifies_tables_unless_overridden() {
+    set_default_schema("analytics");
+    assert_eq!(
+        OrderModel::select().build_compact(),
+        "SELECT orders.id FROM analytics.orders orders"
+    );
+    assert_eq!(
+        OrderModel::select().in_schema("archive").build_compact(),
+        "SELECT orders.id FROM archive.orders orders"
+    );
+    set_default_schema("");
+    assert_eq!(
+        OrderModel::select().build_compact(),
+        "SELECT orders.id FROM orders"
+    );
+}
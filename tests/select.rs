@@ -0,0 +1,737 @@
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("orders")]
+#[primary_key("id")]
+struct OrderModel {
+    id: u32,
+    customer_id: u32,
+    order_status: String,
+}
+
+#[test]
+fn alias_requalifies_columns_but_not_values() {
+    let query = OrderModel::select()
+        .where_order_status("see orders.txt")
+        .alias("o")
+        .build_compact();
+    assert_eq!(
+        query,
+        "SELECT o.id, o.customer_id, o.order_status FROM orders o WHERE o.order_status = 'see orders.txt'"
+    );
+}
+
+#[test]
+fn validate_flags_distinct_order_by_outside_the_projection() {
+    let query = OrderModel::select_str("DISTINCT orders.customer_id").order_by_id_desc();
+    assert_eq!(
+        query.validate(),
+        vec![prkorm::QueryBuildError::OrderByNotSelected(String::from(
+            "orders.id DESC"
+        ))]
+    );
+    let query = OrderModel::select_str("DISTINCT orders.customer_id").order_by_customer_id_asc();
+    assert!(query.try_build().is_ok());
+}
+
+#[test]
+fn empty_in_lists_match_no_rows_unless_skipped() {
+    assert_eq!(
+        OrderModel::select().where_id_in("").build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE 1 = 0"
+    );
+    assert_eq!(
+        OrderModel::select()
+            .empty_in(prkorm::EmptyIn::Skip)
+            .where_id_in("")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders"
+    );
+    let query = OrderModel::select()
+        .empty_in(prkorm::EmptyIn::Error)
+        .where_id_in("");
+    assert_eq!(
+        query.try_build(),
+        Err(prkorm::QueryBuildError::EmptyInList(String::from("id")))
+    );
+    assert!(query.build_compact().ends_with("WHERE 1 = 0"));
+}
+
+#[test]
+fn empty_having_in_lists_stay_in_having() {
+    let query = OrderModel::select_str("customer_id, COUNT(*)")
+        .group_by_customer_id()
+        .having_customer_id_in("");
+    assert_eq!(
+        query.build_compact(),
+        "SELECT customer_id, COUNT(*) FROM orders GROUP BY orders.customer_id HAVING 1 = 0"
+    );
+    assert_eq!(
+        query.clear_having().build_compact(),
+        "SELECT customer_id, COUNT(*) FROM orders GROUP BY orders.customer_id"
+    );
+}
+
+#[test]
+fn seek_after_continues_from_the_cursor() {
+    let token = prkorm::Cursor::new(&["it's", "9"]).encode();
+    let query = OrderModel::select()
+        .seek_after(
+            &[OrderModelColumn::OrderStatus, OrderModelColumn::Id],
+            &token,
+        )
+        .unwrap();
+    assert_eq!(
+        query.build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE (orders.order_status, orders.id) > ('it''s', '9')"
+    );
+    assert_eq!(
+        OrderModel::select().seek_before(&[OrderModelColumn::Id], &token),
+        Err(prkorm::CursorError::ColumnCountMismatch {
+            expected: 1,
+            found: 2
+        })
+    );
+}
+
+#[test]
+fn apply_filter_checks_columns_against_the_model() {
+    let mut filter = prkorm::Filter::new();
+    filter.conditions.push((
+        String::from("order_status"),
+        prkorm::FilterOp::Eq(String::from("new")),
+    ));
+    filter.limit = Some(5);
+    assert_eq!(
+        OrderModel::select()
+            .apply_filter(&filter)
+            .unwrap()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.order_status = 'new' LIMIT 5"
+    );
+    filter
+        .conditions
+        .push((String::from("password"), prkorm::FilterOp::IsNull));
+    assert_eq!(
+        OrderModel::select().apply_filter(&filter),
+        Err(prkorm::FilterError::UnknownColumn(String::from("password")))
+    );
+    assert_eq!(
+        OrderModel::select().order_by_param("id", "sideways"),
+        Err(prkorm::FilterError::InvalidDirection(String::from(
+            "sideways"
+        )))
+    );
+}
+
+#[test]
+fn build_normalized_ignores_condition_order() {
+    let a = OrderModel::select()
+        .where_order_status("new")
+        .where_id(1u32)
+        .build_normalized();
+    let b = OrderModel::select()
+        .where_id(1u32)
+        .where_order_status("new")
+        .where_id(1u32)
+        .build_normalized();
+    assert_eq!(a, b);
+    assert_eq!(
+        a,
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.id = '1' AND orders.order_status = 'new'"
+    );
+}
+
+#[test]
+fn build_terminated_ends_with_one_semicolon() {
+    assert_eq!(
+        OrderModel::select().where_id(1u32).build_terminated(),
+        "SELECT orders.id, orders.customer_id, orders.order_status \nFROM orders  \nWHERE orders.id = '1';"
+    );
+}
+
+#[test]
+fn select_window_as_adds_an_over_clause() {
+    let window = prkorm::Window::new()
+        .partition_by("orders.customer_id")
+        .order_by("orders.id", prkorm::Order::Desc);
+    assert_eq!(
+        OrderModel::select()
+            .select_window_as("ROW_NUMBER()", &window, "position")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status, ROW_NUMBER() OVER (PARTITION BY orders.customer_id ORDER BY orders.id DESC) AS position FROM orders"
+    );
+}
+
+#[test]
+fn order_by_field_dir_takes_an_order() {
+    assert_eq!(
+        OrderModel::select()
+            .order_by_customer_id_dir(prkorm::Order::Desc)
+            .order_by_id_dir(prkorm::Order::default())
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders ORDER BY orders.customer_id DESC, orders.id ASC"
+    );
+}
+
+#[test]
+fn order_by_param_accepts_model_fields() {
+    assert_eq!(
+        OrderModel::select()
+            .order_by_param("order_status", "Desc")
+            .unwrap()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders ORDER BY orders.order_status DESC"
+    );
+}
+
+#[test]
+fn where_expr_and_join_on_take_expressions() {
+    use prkorm::col;
+
+    assert_eq!(
+        OrderModel::select()
+            .join_on("customers", col("customers.id").eq(col("orders.customer_id")))
+            .where_expr(col("orders.order_status").ne("closed"))
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders JOIN customers ON customers.id = orders.customer_id WHERE orders.order_status <> 'closed'"
+    );
+}
+
+#[test]
+fn scope_adds_joins_conditions_and_order() {
+    let scope = prkorm::Scope::new()
+        .join_str("JOIN customers ON customers.id = orders.customer_id")
+        .where_eq("customers.active", 1)
+        .order_by_str("orders.id DESC");
+    assert_eq!(
+        OrderModel::select().scope(&scope).build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders JOIN customers ON customers.id = orders.customer_id WHERE (customers.active = '1') ORDER BY orders.id DESC"
+    );
+}
+
+#[test]
+fn modifiers_render_in_grammar_order() {
+    assert_eq!(
+        OrderModel::select()
+            .modifier(prkorm::SelectModifier::SqlNoCache)
+            .modifier(prkorm::SelectModifier::HighPriority)
+            .modifier(prkorm::SelectModifier::SqlNoCache)
+            .build_compact(),
+        "SELECT HIGH_PRIORITY SQL_NO_CACHE orders.id, orders.customer_id, orders.order_status FROM orders"
+    );
+}
+
+#[test]
+fn build_paged_counts_without_order_or_limit() {
+    let paged = OrderModel::select()
+        .where_order_status("new")
+        .order_by_id_desc()
+        .build_paged(3, 20);
+    assert_eq!(
+        paged.rows,
+        "SELECT orders.id, orders.customer_id, orders.order_status \nFROM orders  \nWHERE orders.order_status = 'new' \nORDER BY orders.id DESC \nLIMIT 20 \nOFFSET 40"
+    );
+    assert_eq!(
+        paged.count,
+        "SELECT COUNT(*) \nFROM orders  \nWHERE orders.order_status = 'new'"
+    );
+}
+
+#[test]
+fn paged_query_results_fill_a_page() {
+    let page = OrderModel::select()
+        .build_paged(1, 20)
+        .into_page(vec![7u32, 8], 20);
+    assert_eq!(page.page, 1);
+    assert_eq!(page.per_page, 20);
+    assert_eq!(page.total_pages(), 1);
+    assert!(!page.has_next() && !page.has_previous());
+    assert_eq!(page.map(|id| id.to_string()).items, ["7", "8"]);
+}
+
+fn describe(query: &impl prkorm::SelectQuery) -> String {
+    format!(
+        "{} {} {}",
+        query.table_name(),
+        query.table_alias(),
+        query.primary_key()
+    )
+}
+
+#[test]
+fn builders_implement_the_query_traits() {
+    let query = OrderModel::select().alias("o");
+    assert_eq!(describe(&query), "orders o id");
+    assert_eq!(prkorm::Query::build(&query), query.build());
+}
+
+#[test]
+fn builders_compare_and_hash_by_query() {
+    use std::collections::HashSet;
+
+    let a = OrderModel::select().where_id(1u32);
+    let b = OrderModel::select().where_id(1u32);
+    assert_eq!(a, b);
+    assert_ne!(a, OrderModel::select().where_id(2u32));
+    let set: HashSet<_> = [a, b].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn build_compact_renders_one_line() {
+    let query = OrderModel::select()
+        .where_id(1u32)
+        .order_by_id_desc()
+        .limit(5);
+    assert_eq!(
+        query.build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.id = '1' ORDER BY orders.id DESC LIMIT 5"
+    );
+    assert!(!query.build_compact().contains('\n'));
+}
+
+#[test]
+fn clear_methods_reset_their_clause() {
+    let query = OrderModel::select()
+        .join_str("JOIN customers ON customers.id = orders.customer_id")
+        .where_id(1u32)
+        .group_by_customer_id()
+        .order_by_id_desc()
+        .limit(5)
+        .offset(10);
+    assert_eq!(
+        query
+            .clear_joins()
+            .clear_where()
+            .clear_group_by()
+            .clear_order_by()
+            .clear_limit()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders"
+    );
+}
+
+#[test]
+fn validate_reports_having_duplicates_and_unordered_pages() {
+    use prkorm::QueryBuildError;
+
+    let query = OrderModel::select()
+        .where_id(1u32)
+        .where_id(1u32)
+        .having_order_status("new")
+        .limit(10)
+        .offset(20);
+    assert_eq!(
+        query.validate(),
+        vec![
+            QueryBuildError::HavingWithoutGroupBy,
+            QueryBuildError::DuplicateCondition(String::from("orders.id = '1'")),
+            QueryBuildError::UnorderedPagination,
+        ]
+    );
+    assert_eq!(
+        query.try_build(),
+        Err(QueryBuildError::HavingWithoutGroupBy)
+    );
+}
+
+#[test]
+fn validate_reports_projection_alias_and_offset_mistakes() {
+    use prkorm::QueryBuildError;
+
+    let query = OrderModel::select_str("orders.id AS x, orders.customer_id AS X")
+        .order_by_id_desc()
+        .offset(5);
+    assert_eq!(
+        query.validate(),
+        vec![
+            QueryBuildError::OffsetWithoutLimit,
+            QueryBuildError::AliasCollision(String::from("x")),
+        ]
+    );
+    assert_eq!(
+        OrderModel::select_str(" ").validate(),
+        vec![QueryBuildError::EmptyProjection]
+    );
+}
+
+#[test]
+fn nulls_first_and_last_use_isnull() {
+    assert_eq!(
+        OrderModel::select()
+            .order_by_customer_id_asc_nulls_last()
+            .order_by_id_desc_nulls_first()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders ORDER BY ISNULL(orders.customer_id), orders.customer_id ASC, ISNULL(orders.id) DESC, orders.id DESC"
+    );
+}
+
+#[test]
+fn order_by_random_samples_rows() {
+    assert_eq!(
+        OrderModel::select().order_by_random().limit(3).build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders ORDER BY RAND() LIMIT 3"
+    );
+}
+
+#[test]
+fn from_partition_restricts_the_table() {
+    assert_eq!(
+        OrderModel::select()
+            .from_partition(&["p2023", "p2024"])
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders PARTITION (p2023, p2024)"
+    );
+}
+
+#[test]
+fn comments_are_appended_url_encoded() {
+    assert_eq!(
+        OrderModel::select()
+            .comment("route", "/orders/{id}")
+            .comment("action", "show")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders /*action='show',route='%2Forders%2F%7Bid%7D'*/"
+    );
+}
+
+#[test]
+fn index_hints_follow_the_table() {
+    assert_eq!(
+        OrderModel::select()
+            .force_index(&["idx_status"])
+            .ignore_index(&["PRIMARY"])
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders FORCE INDEX (idx_status) IGNORE INDEX (PRIMARY)"
+    );
+}
+
+#[test]
+fn as_subquery_joins_as_a_derived_table() {
+    let totals = OrderModel::select_str("customer_id, COUNT(*) AS total")
+        .group_by_customer_id()
+        .as_subquery("totals");
+    assert_eq!(
+        totals,
+        "(SELECT customer_id, COUNT(*) AS total FROM orders GROUP BY orders.customer_id) AS totals"
+    );
+    assert_eq!(
+        OrderModel::select()
+            .left_join_subquery(&totals, "totals.customer_id = orders.customer_id")
+            .build_compact(),
+        format!(
+            "SELECT orders.id, orders.customer_id, orders.order_status FROM orders LEFT JOIN {} ON totals.customer_id = orders.customer_id",
+            totals
+        )
+    );
+}
+
+#[test]
+fn group_by_takes_several_expressions() {
+    assert_eq!(
+        OrderModel::select_str("COUNT(*)")
+            .group_by(&["YEAR(orders.created_at)"])
+            .group_by_columns(&[OrderModelColumn::CustomerId, OrderModelColumn::OrderStatus])
+            .build_compact(),
+        "SELECT COUNT(*) FROM orders GROUP BY YEAR(orders.created_at), orders.customer_id, orders.order_status"
+    );
+}
+
+#[test]
+fn also_from_adds_comma_joined_tables() {
+    assert_eq!(
+        OrderModel::select()
+            .also_from("customers")
+            .also_from_as("addresses", "a")
+            .where_str("customers.id = orders.customer_id")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders, customers, addresses a WHERE customers.id = orders.customer_id"
+    );
+}
+
+#[test]
+fn join_json_table_quotes_its_paths() {
+    assert_eq!(
+        OrderModel::select()
+            .join_json_table(
+                "orders.tags",
+                "$[*]",
+                &[("tag", "VARCHAR(32)", "$.name")],
+                "t"
+            )
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders CROSS JOIN JSON_TABLE(orders.tags, '$[*]' COLUMNS (tag VARCHAR(32) PATH '$.name')) AS t"
+    );
+}
+
+#[test]
+fn any_and_all_compare_against_a_subquery() {
+    let customers = OrderModel::select_customer_id().where_order_status("vip");
+    assert_eq!(
+        OrderModel::select_id()
+            .where_customer_id_gt_all(&customers)
+            .where_id_any("=", "SELECT 1")
+            .build_compact(),
+        format!(
+            "SELECT orders.id FROM orders WHERE orders.customer_id > ALL ({}) AND orders.id = ANY (SELECT 1)",
+            customers
+        )
+    );
+}
+
+#[test]
+fn or_having_joins_the_previous_having_condition() {
+    assert_eq!(
+        OrderModel::select_str("customer_id, COUNT(*) AS total")
+            .group_by_customer_id()
+            .having_str("total > 10")
+            .or_having_customer_id(1u32)
+            .or_having_expr(prkorm::col("total").lt(2))
+            .build_compact(),
+        "SELECT customer_id, COUNT(*) AS total FROM orders GROUP BY orders.customer_id HAVING ((total > 10 OR orders.customer_id = '1') OR total < 2)"
+    );
+}
+
+#[test]
+fn select_joined_prefixes_the_joined_columns() {
+    assert_eq!(
+        OrderModel::select_id()
+            .left_join_by_customer_id("crm.customers", "id")
+            .select_joined("crm.customers", &["name", "`key`"])
+            .build_compact(),
+        "SELECT orders.id, crm.customers.name AS customers_name, crm.customers.`key` AS customers_key FROM orders LEFT JOIN crm.customers ON crm.customers.id = orders.customer_id"
+    );
+}
+
+#[test]
+fn merge_filters_from_reuses_another_builders_filters() {
+    let base = OrderModel::select()
+        .where_order_status("open")
+        .order_by_id_desc();
+    assert_eq!(
+        OrderModel::select_str("COUNT(*)")
+            .where_order_status("open")
+            .merge_filters_from(&base)
+            .build_compact(),
+        "SELECT COUNT(*) FROM orders WHERE orders.order_status = 'open' ORDER BY orders.id DESC"
+    );
+}
+
+#[test]
+fn builders_convert_into_strings() {
+    let sql: String = OrderModel::select().where_id(1u32).into();
+    assert_eq!(sql, OrderModel::select().where_id(1u32).build());
+}
+
+#[test]
+fn max_execution_time_is_an_optimizer_hint() {
+    assert_eq!(
+        OrderModel::select_id()
+            .max_execution_time(500)
+            .build_compact(),
+        "SELECT /*+ MAX_EXECUTION_TIME(500) */ orders.id FROM orders"
+    );
+}
+
+#[test]
+fn into_variables_stores_the_row() {
+    assert_eq!(
+        OrderModel::select_id()
+            .select_customer_id()
+            .where_id(1u32)
+            .into_variables(&["@id", "@customer"])
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id FROM orders WHERE orders.id = '1' INTO @id, @customer"
+    );
+}
+
+#[test]
+fn collate_helpers_apply_a_collation() {
+    assert_eq!(
+        OrderModel::select_id()
+            .where_order_status_eq_collate("NEW", "utf8mb4_general_ci")
+            .order_by_order_status_collate("utf8mb4_bin", prkorm::Order::Asc)
+            .build_compact(),
+        "SELECT orders.id FROM orders WHERE orders.order_status = 'NEW' COLLATE utf8mb4_general_ci ORDER BY orders.order_status COLLATE utf8mb4_bin ASC"
+    );
+}
+
+#[test]
+fn where_tuple_in_matches_row_values() {
+    assert_eq!(
+        OrderModel::select_id()
+            .where_tuple_in(&["customer_id", "order_status"], &[(1, "new"), (2, "it's")])
+            .build_compact(),
+        "SELECT orders.id FROM orders WHERE (orders.customer_id, orders.order_status) IN (('1', 'new'), ('2', 'it''s'))"
+    );
+    assert_eq!(
+        OrderModel::select_id()
+            .where_tuple_in::<(u32, u32)>(&["customer_id", "id"], &[])
+            .build_compact(),
+        "SELECT orders.id FROM orders WHERE 1 = 0"
+    );
+}
+
+#[test]
+fn bit_flag_helpers_mask_integer_fields() {
+    assert_eq!(
+        OrderModel::select_id()
+            .where_customer_id_has_flag(4)
+            .where_id_bitand(3, "<>", 0)
+            .build_compact(),
+        "SELECT orders.id FROM orders WHERE orders.customer_id & 4 = 4 AND orders.id & 3 <> 0"
+    );
+}
+
+#[test]
+fn order_by_alias_is_checked_against_the_projection() {
+    let query = OrderModel::select_str("customer_id, COUNT(*) AS total")
+        .group_by_customer_id()
+        .order_by_alias("total", prkorm::Order::Desc);
+    assert_eq!(
+        query.try_build().unwrap(),
+        "SELECT customer_id, COUNT(*) AS total \nFROM orders  \nGROUP BY orders.customer_id \nORDER BY total DESC"
+    );
+    assert_eq!(
+        query
+            .order_by_alias("missing", prkorm::Order::Asc)
+            .validate(),
+        vec![prkorm::QueryBuildError::UnknownAlias(String::from(
+            "missing"
+        ))]
+    );
+}
+
+#[test]
+fn group_by_expr_and_alias_are_validated() {
+    let query = OrderModel::select_str("YEAR(created_at) AS year, COUNT(*)")
+        .group_by_alias("year")
+        .group_by_expr(" MONTH(created_at) ");
+    assert_eq!(
+        query.build_compact(),
+        "SELECT YEAR(created_at) AS year, COUNT(*) FROM orders GROUP BY year, MONTH(created_at)"
+    );
+    assert!(query.validate().is_empty());
+    assert_eq!(
+        OrderModel::select_str("COUNT(*)")
+            .group_by_expr("COUNT(*)")
+            .group_by_alias("nope")
+            .validate(),
+        vec![
+            prkorm::QueryBuildError::InvalidGroupBy(String::from("COUNT(*)")),
+            prkorm::QueryBuildError::UnknownAlias(String::from("nope")),
+        ]
+    );
+}
+
+#[derive(Table)]
+#[table_name("customers")]
+struct Customer {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn models_join_under_aliases() {
+    assert_eq!(
+        OrderModel::select()
+            .left_join_by_customer_id_model_as::<Customer>("buyer", "id")
+            .inner_join_by_customer_id_model_as::<Customer>("payer", "id")
+            .select_model_as::<Customer>("buyer")
+            .where_str(&format!("{} = 'ann'", Customer::name_of("buyer")))
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status, buyer.id AS buyer_id, buyer.name AS buyer_name FROM orders LEFT JOIN customers buyer ON buyer.id = orders.customer_id INNER JOIN customers payer ON payer.id = orders.customer_id WHERE buyer.name = 'ann'"
+    );
+    assert_eq!(
+        OrderModel::select()
+            .inner_join_model_as::<Customer>("c", "order_id")
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders INNER JOIN customers c ON c.order_id = orders.id"
+    );
+}
+
+#[test]
+fn where_from_map_sorts_and_whitelists_columns() {
+    let filters = std::collections::HashMap::from([
+        (String::from("order_status"), String::from("new")),
+        (String::from("customer_id"), String::from("7")),
+    ]);
+    assert_eq!(
+        OrderModel::select()
+            .where_from_map(&filters)
+            .unwrap()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.customer_id = '7' AND orders.order_status = 'new'"
+    );
+    let filters = std::collections::HashMap::from([
+        (
+            String::from("id"),
+            (String::from("gte"), String::from("10")),
+        ),
+        (
+            String::from("order_status"),
+            (String::from("in"), String::from("new, paid")),
+        ),
+    ]);
+    assert_eq!(
+        OrderModel::select()
+            .where_from_map_ops(&filters)
+            .unwrap()
+            .build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders WHERE orders.id >= '10' AND orders.order_status IN ('new', 'paid')"
+    );
+    let filters = std::collections::HashMap::from([(
+        String::from("id"),
+        (String::from("between"), String::from("1")),
+    )]);
+    assert_eq!(
+        OrderModel::select().where_from_map_ops(&filters),
+        Err(prkorm::FilterError::UnknownOperator(String::from(
+            "between"
+        )))
+    );
+    let filters = std::collections::HashMap::from([(String::from("password"), String::from("x"))]);
+    assert_eq!(
+        OrderModel::select().where_from_map(&filters),
+        Err(prkorm::FilterError::UnknownColumn(String::from("password")))
+    );
+}
+
+#[test]
+fn debug_shows_the_rendered_sql() {
+    assert_eq!(
+        format!("{:?}", OrderModel::select().where_id(1u32)),
+        "OrderModelSelectBuilder { sql: \"SELECT orders.id, orders.customer_id, orders.order_status \\nFROM orders  \\nWHERE orders.id = '1'\" }"
+    );
+}
+
+#[test]
+fn plain_selects_start_with_the_select_prefix() {
+    assert_eq!(
+        OrderModel::SELECT_PREFIX,
+        "SELECT orders.id, orders.customer_id, orders.order_status \nFROM orders "
+    );
+    assert!(OrderModel::select()
+        .where_id(1u32)
+        .build()
+        .starts_with(OrderModel::SELECT_PREFIX));
+    assert!(!OrderModel::select()
+        .alias("o")
+        .build()
+        .starts_with(OrderModel::SELECT_PREFIX));
+    assert_eq!(
+        OrderModel::select().select_str("NOW() AS now").build(),
+        "SELECT orders.id, orders.customer_id, orders.order_status, NOW() AS now \nFROM orders "
+    );
+    assert_eq!(
+        OrderModel::select().table_prefix("app_").build(),
+        "SELECT orders.id, orders.customer_id, orders.order_status \nFROM app_orders orders"
+    );
+    assert_eq!(
+        OrderModel::select_all_columns().build(),
+        OrderModel::SELECT_PREFIX
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "serde")]
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("orders")]
+#[primary_key("id")]
+struct OrderModel {
+    id: u32,
+    customer_id: u32,
+    order_status: String,
+}
+
+#[test]
+fn builders_round_trip_through_serde() {
+    let select = OrderModel::select()
+        .where_order_status("shipped")
+        .order_by_id_desc()
+        .limit(10);
+    let json = serde_json::to_string(&select).unwrap();
+    let restored: OrderModelSelectBuilder = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, select);
+    assert_eq!(restored.build_compact(), select.build_compact());
+
+    let insert = OrderModel::insert()
+        .insert_to_customer_id(7u32)
+        .insert_to_order_status("new");
+    let json = serde_json::to_string(&insert).unwrap();
+    let restored: OrderModelInsertBuilder = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.build(), insert.build());
+
+    let update = OrderModel::update().update_order_status_with_value("paid");
+    let json = serde_json::to_string(&update).unwrap();
+    let restored: OrderModelUpdateBuilder = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.update_where_id_eq(1u32),
+        update.update_where_id_eq(1u32)
+    );
+
+    let delete = OrderModel::delete().low_priority();
+    let json = serde_json::to_string(&delete).unwrap();
+    let restored: OrderModelDeleteBuilder = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.delete_where_id_eq(1u32),
+        delete.delete_where_id_eq(1u32)
+    );
+}
+
+#[test]
+fn observers_are_not_serialized() {
+    let select = OrderModel::select().on_build(|_| panic!("restored builders have no observer"));
+    let json = serde_json::to_string(&select).unwrap();
+    let restored: OrderModelSelectBuilder = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.build_compact(),
+        "SELECT orders.id, orders.customer_id, orders.order_status FROM orders"
+    );
+}
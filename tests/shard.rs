@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("events")]
+#[sharded_by("account_id", shards = 16)]
+struct Event {
+    id: u64,
+    account_id: u64,
+}
+
+#[test]
+fn shard_for_suffixes_the_table() {
+    assert_eq!(Event::shard_key(), "account_id");
+    assert_eq!(Event::shard_count(), 16);
+    assert_eq!(
+        Event::select().shard_for(23).build_compact(),
+        "SELECT events.id, events.account_id FROM events_07 events"
+    );
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use prkorm::{with_tenant, Table};
+
+#[derive(Table)]
+#[table_name("invoices")]
+#[tenant_key("tenant_id")]
+struct Invoice {
+    id: u64,
+    tenant_id: u64,
+}
+
+#[test]
+fn tenant_is_resolved_when_the_query_is_built() {
+    let query = Invoice::select().where_id(7u64);
+    assert_eq!(
+        with_tenant(1, || query.build_compact()),
+        "SELECT invoices.id, invoices.tenant_id FROM invoices WHERE invoices.tenant_id = '1' AND (invoices.id = '7')"
+    );
+    assert_eq!(
+        with_tenant(2, || query.to_string()),
+        "SELECT invoices.id, invoices.tenant_id \nFROM invoices  \nWHERE invoices.tenant_id = '2' AND (invoices.id = '7')"
+    );
+}
+
+#[test]
+fn or_conditions_stay_inside_the_tenant() {
+    let query = Invoice::select()
+        .where_str("invoices.id = 1 OR invoices.id = 2")
+        .where_id(3u64);
+    assert_eq!(
+        with_tenant(7, || query.build_compact()),
+        "SELECT invoices.id, invoices.tenant_id FROM invoices WHERE invoices.tenant_id = '7' AND (invoices.id = 1 OR invoices.id = 2 AND invoices.id = '3')"
+    );
+}
+
+#[test]
+fn tenant_scope_survives_clear_where() {
+    let query = Invoice::select().where_id(7u64).clear_where();
+    assert_eq!(
+        with_tenant(3, || query.build_compact()),
+        "SELECT invoices.id, invoices.tenant_id FROM invoices WHERE invoices.tenant_id = '3'"
+    );
+}
+
+#[test]
+fn write_builders_are_scoped_to_the_tenant() {
+    with_tenant("it's", || {
+        assert_eq!(
+            Invoice::delete().delete_where_id_eq(7u64),
+            "DELETE FROM invoices WHERE id = '7' AND tenant_id = 'it''s'"
+        );
+        assert_eq!(
+            Invoice::update()
+                .update_id_with_value(8u64)
+                .where_str("id = 7"),
+            "UPDATE invoices SET id = '8' WHERE (id = 7) AND tenant_id = 'it''s'"
+        );
+    });
+}
+
+#[test]
+fn building_without_a_tenant_panics() {
+    let query = Invoice::select();
+    assert!(std::panic::catch_unwind(|| query.build()).is_err());
+    assert!(std::panic::catch_unwind(|| Invoice::delete().delete_where_id_eq(1u64)).is_err());
+    assert!(format!("{:?}", query).contains("<no tenant set>"));
+}
+
+#[test]
+fn try_build_and_display_report_a_missing_tenant() {
+    let query = Invoice::select().on_build(|_| panic!("nothing was built"));
+    assert_eq!(
+        query.try_build(),
+        Err(prkorm::QueryBuildError::NoTenant(String::from("invoices")))
+    );
+    assert_eq!(query.to_string(), "<no tenant set>");
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "uuid")]
+#![allow(dead_code)]
+
+use prkorm::Table;
+use uuid::Uuid;
+
+#[derive(Table)]
+#[table_name("devices")]
+struct Device {
+    #[uuid_binary]
+    id: Uuid,
+    owner: Option<Uuid>,
+}
+
+#[test]
+fn binary_uuids_go_through_uuid_to_bin() {
+    let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    assert_eq!(
+        Device::select().where_id(id).build_compact(),
+        "SELECT BIN_TO_UUID(devices.id) AS id, devices.owner FROM devices WHERE devices.id = UUID_TO_BIN('67e55044-10b1-426f-9247-bb680e5fe0c8')"
+    );
+    assert_eq!(
+        Device::insert()
+            .insert_to_id(id)
+            .insert_to_owner(None::<Uuid>)
+            .build_compact(),
+        "INSERT INTO devices (id, owner) VALUES (UUID_TO_BIN('67e55044-10b1-426f-9247-bb680e5fe0c8'), NULL)"
+    );
+}
@@ -0,0 +1,329 @@
+#![allow(dead_code)]
+
+use prkorm::Table;
+
+#[derive(Table)]
+#[table_name("accounts")]
+#[primary_key("id")]
+struct Account {
+    id: u32,
+    owner: String,
+    nickname: Option<String>,
+    #[version]
+    version: u32,
+}
+
+#[test]
+fn update_from_bumps_the_version_and_renders_optional_fields() {
+    let account = Account {
+        id: 3,
+        owner: String::from("ann"),
+        nickname: None,
+        version: 7,
+    };
+    assert_eq!(
+        account.update_from(),
+        "UPDATE accounts SET nickname = NULL, owner = 'ann', version = version + 1 \nWHERE id = '3' AND version = '7'"
+    );
+    let account = Account {
+        nickname: Some(String::from("it's me")),
+        ..account
+    };
+    assert!(account.update_from().contains("nickname = 'it''s me'"));
+}
+
+#[derive(Table)]
+#[table_name("people")]
+#[table(repository)]
+#[primary_key("id")]
+struct Person {
+    id: u32,
+    email: Option<String>,
+}
+
+struct People;
+
+impl PersonRepository for People {}
+
+#[test]
+fn repository_renders_optional_fields_and_is_dyn_compatible() {
+    let repository: Box<dyn PersonRepository> = Box::new(People);
+    assert_eq!(
+        repository.insert_one(&Person { id: 4, email: None }),
+        "INSERT INTO people\n(email, id) VALUES  (NULL, '4')"
+    );
+    assert_eq!(
+        repository.find_by_id(4),
+        "SELECT people.id, people.email \nFROM people  \nWHERE people.id = '4'"
+    );
+    assert_eq!(
+        repository.delete_by_id(4),
+        "DELETE FROM people WHERE id = '4'"
+    );
+}
+
+#[test]
+fn unfinished_write_builders_only_debug_print() {
+    assert_eq!(
+        format!("{:?}", Account::delete()),
+        "AccountDeleteBuilder { sql: \"DELETE FROM accounts WHERE <condition>\" }"
+    );
+    assert_eq!(
+        format!("{:?}", Account::update().update_owner_with_value("bob")),
+        "AccountUpdateBuilder { sql: \"UPDATE accounts SET owner = 'bob' WHERE <condition>\" }"
+    );
+    assert_eq!(
+        Account::update()
+            .update_owner_with_value("bob")
+            .update_where_id_eq(3u32),
+        "UPDATE accounts SET owner = 'bob' \nWHERE id = '3'"
+    );
+}
+
+#[test]
+fn empty_insert_renders_nothing() {
+    assert_eq!(Account::insert().build(), "");
+    assert_eq!(
+        Account::insert()
+            .comment("route", "/accounts")
+            .on_build(|_| panic!("nothing was built"))
+            .build(),
+        ""
+    );
+    assert_eq!(
+        format!("{:?}", Account::insert()),
+        "AccountInsertBuilder { sql: \"INSERT INTO accounts\" }"
+    );
+}
+
+#[derive(Table)]
+#[table_name("api_keys")]
+struct ApiKey {
+    id: u32,
+    secret: Vec<u8>,
+    salt: Option<[u8; 2]>,
+}
+
+#[test]
+fn byte_fields_render_as_hex_literals() {
+    assert_eq!(
+        ApiKey::insert()
+            .insert_to_secret(vec![0xca, 0xfe])
+            .insert_to_salt(None::<[u8; 2]>)
+            .build(),
+        "INSERT INTO api_keys\n(salt, secret) VALUES  (NULL, X'CAFE')"
+    );
+    assert_eq!(
+        ApiKey::delete().delete_where_secret_eq(vec![0xbe, 0xef]),
+        "DELETE FROM api_keys WHERE secret = X'BEEF'"
+    );
+}
+
+#[test]
+fn scope_conditions_are_anded_to_writes() {
+    let scope = prkorm::Scope::new().where_str("deleted_at IS NULL");
+    assert_eq!(
+        Account::delete().scope(&scope).delete_where_id_eq(3u32),
+        "DELETE FROM accounts WHERE id = '3' AND (deleted_at IS NULL)"
+    );
+}
+
+#[test]
+fn or_in_a_scope_stays_inside_it() {
+    let scope = prkorm::Scope::new().where_str("owner = 'x' OR owner = 'y'");
+    assert_eq!(
+        Account::delete().scope(&scope).delete_where_id_eq(5u32),
+        "DELETE FROM accounts WHERE id = '5' AND (owner = 'x' OR owner = 'y')"
+    );
+    assert_eq!(
+        Account::update()
+            .scope(&scope)
+            .update_nickname_with_value("z")
+            .update_where_id_eq(5u32),
+        "UPDATE accounts SET nickname = 'z' \nWHERE id = '5' AND (owner = 'x' OR owner = 'y')"
+    );
+}
+
+#[test]
+#[should_panic(expected = "only apply to select builders")]
+fn write_builders_reject_scopes_with_joins() {
+    let scope = prkorm::Scope::new().join_str("JOIN owners ON owners.id = accounts.owner");
+    let _ = Account::delete().scope(&scope);
+}
+
+#[test]
+#[should_panic(expected = "only apply to select builders")]
+fn write_builders_reject_scopes_with_order_by() {
+    let scope = prkorm::Scope::new().order_by_str("id DESC");
+    let _ = Account::update().scope(&scope);
+}
+
+#[test]
+fn insert_priority_modifiers_replace_each_other() {
+    assert_eq!(
+        Account::insert()
+            .modifier(prkorm::InsertModifier::Ignore)
+            .modifier(prkorm::InsertModifier::LowPriority)
+            .modifier(prkorm::InsertModifier::HighPriority)
+            .insert_to_owner("ann")
+            .build(),
+        "INSERT HIGH_PRIORITY IGNORE INTO accounts\n(owner) VALUES  ('ann')"
+    );
+}
+
+fn table_of(query: &impl prkorm::WriteQuery) -> &str {
+    query.table_name()
+}
+
+#[test]
+fn insert_builders_implement_write_query() {
+    let insert = Account::insert().insert_to_owner("ann");
+    assert_eq!(table_of(&insert), "accounts");
+    assert_eq!(prkorm::Query::build(&insert), insert.to_string());
+}
+
+#[test]
+fn insert_build_compact_renders_one_line() {
+    assert_eq!(
+        Account::insert().insert_to_owner("ann").build_compact(),
+        "INSERT INTO accounts (owner) VALUES ('ann')"
+    );
+}
+
+#[test]
+fn from_partition_restricts_writes() {
+    assert_eq!(
+        Account::delete()
+            .from_partition(&["p1"])
+            .delete_where_id_eq(1u32),
+        "DELETE FROM accounts PARTITION (p1) WHERE id = '1'"
+    );
+}
+
+#[test]
+fn comments_are_appended_to_writes() {
+    assert_eq!(
+        Account::delete()
+            .comment("job", "purge")
+            .delete_where_id_eq(1u32),
+        "DELETE FROM accounts WHERE id = '1' /*job='purge'*/"
+    );
+}
+
+#[test]
+fn delete_condition_variants() {
+    assert_eq!(
+        Account::delete().delete_where_version_gt(3u32),
+        "DELETE FROM accounts WHERE version > '3'"
+    );
+    assert_eq!(
+        Account::delete().delete_where_owner_like("a%"),
+        "DELETE FROM accounts WHERE owner LIKE 'a%'"
+    );
+    assert_eq!(
+        Account::delete().delete_where_nickname_is_null(),
+        "DELETE FROM accounts WHERE nickname IS NULL"
+    );
+}
+
+#[test]
+fn update_condition_variants() {
+    let update = Account::update().update_owner_with_value("o'neil");
+    assert_eq!(
+        update.clone().update_where_version_lt(3u32),
+        "UPDATE accounts SET owner = 'o''neil' \nWHERE version < '3'"
+    );
+    assert_eq!(
+        update.clone().update_where_id_condition(">=", 10u32),
+        "UPDATE accounts SET owner = 'o''neil' \nWHERE id >= '10'"
+    );
+    assert_eq!(
+        update.update_where_nickname_is_null(),
+        "UPDATE accounts SET owner = 'o''neil' \nWHERE nickname IS NULL"
+    );
+}
+
+#[derive(Table)]
+#[table_name("invoices")]
+#[table(repository)]
+#[primary_key("id")]
+struct Invoice {
+    #[generated]
+    id: u32,
+    amount: u32,
+    #[generated]
+    total_with_tax: u32,
+    #[version]
+    version: u32,
+}
+
+struct Invoices;
+
+impl InvoiceRepository for Invoices {}
+
+#[test]
+fn generated_columns_are_left_out_of_writes() {
+    let invoice = Invoice {
+        id: 1,
+        amount: 10,
+        total_with_tax: 12,
+        version: 2,
+    };
+    assert_eq!(
+        Invoices.insert_one(&invoice),
+        "INSERT INTO invoices\n(amount, version) VALUES  ('10', '2')"
+    );
+    assert_eq!(
+        invoice.update_from(),
+        "UPDATE invoices SET amount = '10', version = version + 1 \nWHERE id = '1' AND version = '2'"
+    );
+    assert_eq!(
+        Invoice::select().build_compact(),
+        "SELECT invoices.id, invoices.amount, invoices.total_with_tax, invoices.version FROM invoices"
+    );
+}
+
+#[derive(Table)]
+#[table_name("page_views")]
+struct PageView {
+    path: String,
+    day: String,
+    views: i64,
+}
+
+#[test]
+fn upsert_increment_adds_to_existing_counters() {
+    assert_eq!(
+        PageView::upsert_increment(&[("path", "/"), ("day", "2024-03-01")], &[("views", 1)]),
+        "INSERT INTO page_views\n(day, path, views) VALUES  ('2024-03-01', '/', 1) \nON DUPLICATE KEY UPDATE views = views + VALUES(views)"
+    );
+}
+
+#[test]
+fn insert_builders_convert_into_strings() {
+    let sql: String = Account::insert().insert_to_owner("ann").into();
+    assert_eq!(sql, "INSERT INTO accounts\n(owner) VALUES  ('ann')");
+}
+
+#[test]
+fn delete_modifiers_follow_the_keyword() {
+    assert_eq!(
+        Account::delete()
+            .ignore()
+            .quick()
+            .low_priority()
+            .delete_where_id_eq(1u32),
+        "DELETE LOW_PRIORITY QUICK IGNORE FROM accounts WHERE id = '1'"
+    );
+}
+
+#[test]
+fn update_ignore_follows_the_keyword() {
+    assert_eq!(
+        Account::update()
+            .ignore()
+            .update_owner_with_value("sam")
+            .update_where_id_eq(1u32),
+        "UPDATE IGNORE accounts SET owner = 'sam' \nWHERE id = '1'"
+    );
+}